@@ -0,0 +1,159 @@
+//! Generates the C header for `src/ffi.rs`
+//!
+//! Written by Trevor Leibert
+//!
+//! The header is written to `$OUT_DIR/aoc.h`; `tests/ffi.rs` checks that
+//! `include/aoc.h` matches it.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const SOURCE: &str = "src/ffi.rs";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SOURCE);
+    let source = fs::read_to_string(SOURCE).expect("couldn't read src/ffi.rs");
+    let header = generate(&source);
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("aoc.h"), header).unwrap();
+}
+
+/// turns the `pub const`s and `extern "C"` functions of the source into C
+/// declarations, carrying their doc comments along
+fn generate(source: &str) -> String {
+    let mut header = String::from(
+        "/* Generated by build.rs from src/ffi.rs, do not edit by hand */\n\n\
+         #ifndef AOC_H\n#define AOC_H\n\n\
+         #include <stddef.h>\n#include <stdint.h>\n\n\
+         #ifdef __cplusplus\nextern \"C\" {\n#endif\n",
+    );
+
+    let mut docs: Vec<&str> = Vec::new();
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc.strip_prefix(' ').unwrap_or(doc));
+        } else if line.starts_with("#[") {
+            continue;
+        } else if let Some(decl) = line.strip_prefix("pub const ") {
+            header.push_str(&comment(&docs));
+            header.push_str(&constant(decl));
+            docs.clear();
+        } else if line.contains("extern \"C\" fn ") {
+            // signatures may be wrapped over several lines by rustfmt
+            let mut signature = line.to_string();
+            while !signature.ends_with('{') {
+                signature.push_str(lines.next().expect("unterminated signature").trim());
+            }
+            header.push_str(&comment(&docs));
+            header.push_str(&function(&signature));
+            docs.clear();
+        } else {
+            docs.clear();
+        }
+    }
+
+    header.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif /* AOC_H */\n");
+    header
+}
+
+fn comment(docs: &[&str]) -> String {
+    if docs.is_empty() {
+        return "\n".to_string();
+    }
+
+    let mut out = String::from("\n/**\n");
+    for doc in docs {
+        let doc = doc.replace('`', "");
+        if doc.is_empty() {
+            out.push_str(" *\n");
+        } else {
+            out.push_str(&format!(" * {}\n", doc));
+        }
+    }
+    out.push_str(" */\n");
+    out
+}
+
+/// `NAME: ty = value;` to a `#define`
+fn constant(decl: &str) -> String {
+    let (name, rest) = decl.split_once(':').expect("constant without a type");
+    let (ty, value) = rest.split_once('=').expect("constant without a value");
+    let value = value.trim().trim_end_matches(';');
+    let suffix = if c_type(ty.trim()).starts_with("uint") {
+        "u"
+    } else {
+        ""
+    };
+    format!("#define {} {}{}\n", name.trim(), value, suffix)
+}
+
+/// `pub unsafe extern "C" fn name(a: T, ...) -> R {` to a prototype
+fn function(signature: &str) -> String {
+    let (_, rest) = signature.split_once(" fn ").unwrap();
+    let (name, rest) = rest.split_once('(').unwrap();
+    let (args, rest) = rest.rsplit_once(')').unwrap();
+    let ret = rest
+        .trim()
+        .trim_end_matches('{')
+        .trim()
+        .strip_prefix("->")
+        .map_or("void".to_string(), |ty| c_type(ty.trim()));
+
+    let args: Vec<String> = args
+        .split(',')
+        .map(str::trim)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| {
+            let (name, ty) = arg.split_once(':').expect("argument without a type");
+            declare(&c_type(ty.trim()), name.trim())
+        })
+        .collect();
+    let args = if args.is_empty() {
+        "void".to_string()
+    } else {
+        args.join(", ")
+    };
+
+    format!("{}({});\n", declare(&ret, name.trim()), args)
+}
+
+/// puts a name after a C type, keeping pointer stars against the name
+fn declare(ty: &str, name: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{}{}", ty, name)
+    } else {
+        format!("{} {}", ty, name)
+    }
+}
+
+fn c_type(ty: &str) -> String {
+    if let Some(inner) = ty.strip_prefix("*const ") {
+        return format!("const {}", pointer(&c_type(inner)));
+    }
+    if let Some(inner) = ty.strip_prefix("*mut ") {
+        return pointer(&c_type(inner));
+    }
+
+    match ty {
+        "u8" => "uint8_t",
+        "u32" => "uint32_t",
+        "u64" => "uint64_t",
+        "i32" => "int32_t",
+        "i64" => "int64_t",
+        "usize" => "size_t",
+        "c_char" => "char",
+        other => panic!("no C type for `{}` in src/ffi.rs", other),
+    }
+    .to_string()
+}
+
+fn pointer(ty: &str) -> String {
+    if ty.ends_with('*') {
+        format!("{}*", ty)
+    } else {
+        format!("{} *", ty)
+    }
+}
@@ -0,0 +1,83 @@
+/* Generated by build.rs from src/ffi.rs, do not edit by hand */
+
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Version of the C API, bumped whenever a signature or status code changes
+ */
+#define AOC_ABI_VERSION 1u
+
+/**
+ * The answer was written to out
+ */
+#define AOC_OK 0
+
+/**
+ * No solution is registered for the day
+ */
+#define AOC_UNKNOWN_DAY 1
+
+/**
+ * The part wasn't 1 or 2
+ */
+#define AOC_UNKNOWN_PART 2
+
+/**
+ * The input wasn't valid UTF-8
+ */
+#define AOC_INVALID_INPUT 3
+
+/**
+ * The part hasn't been solved yet
+ */
+#define AOC_UNSOLVED 4
+
+/**
+ * The solver failed, usually because the input was malformed
+ */
+#define AOC_FAILED 5
+
+/**
+ * The input pointer was null
+ */
+#define AOC_NULL_POINTER 6
+
+/**
+ * Returns AOC_ABI_VERSION as compiled into the library
+ */
+uint32_t aoc_abi_version(void);
+
+/**
+ * Solves one part of a day against input_len bytes of UTF-8 input.
+ * Returns a status code. On AOC_OK, *out is the answer, otherwise
+ * it's a message saying what went wrong. Either way the caller owns the
+ * string and must release it with aoc_string_free. out may be null
+ * if the caller only wants the status.
+ *
+ * # Safety
+ * input must point to input_len readable bytes, and out must be
+ * null or valid to write a pointer to.
+ */
+int32_t aoc_solve(uint32_t day, uint32_t part, const uint8_t *input, size_t input_len, char **out);
+
+/**
+ * Frees a string returned by this library. Null is ignored.
+ *
+ * # Safety
+ * s must be null or a string from this library that hasn't been freed.
+ */
+void aoc_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AOC_H */
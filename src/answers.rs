@@ -0,0 +1,181 @@
+//! Verified answers, and the inputs they were verified against
+//!
+//! Written by Trevor Leibert
+//!
+//! Answers are kept in `inputs/answers.txt`, one line per part, next to the
+//! checksum of the input they were verified against:
+//!
+//! ```text
+//! # day part checksum answer
+//! 9 1 3f1c0a96e0b5a2d4 480
+//! ```
+//!
+//! Newlines in answers are written as `\n` and backslashes as `\\`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+use crate::solution::{Error, Run};
+
+/// A verified answer to one part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    /// checksum of the input the answer was verified against
+    pub checksum: String,
+    pub answer: String,
+}
+
+/// How an answer compares with the verified one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// there's nothing to compare, either nothing was recorded or the part didn't finish
+    Unchecked,
+    /// same input, same answer
+    Verified,
+    /// same input, but a different answer to the one recorded
+    Wrong { expected: String },
+    /// the input isn't the one the answer was verified against
+    InputChanged { recorded: String },
+}
+
+/// Every verified answer, by day and part
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(usize, usize), Answer>,
+}
+
+impl Answers {
+    /// reads the answers file, a missing file has no answers in it
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(Error::Input {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        Self::parse(&text).map_err(|msg| Error::Input {
+            path: path.to_path_buf(),
+            source: io::Error::new(ErrorKind::InvalidData, msg),
+        })
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut answers = Self::default();
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let bad = || format!("line {} isn't `day part checksum answer`", idx + 1);
+            let mut fields = line.splitn(4, ' ');
+            let day = fields
+                .next()
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(bad)?;
+            let part = fields
+                .next()
+                .and_then(|part| part.parse().ok())
+                .ok_or_else(bad)?;
+            let checksum = fields.next().ok_or_else(bad)?.to_string();
+            let answer = unescape(fields.next().ok_or_else(bad)?);
+            answers
+                .answers
+                .insert((day, part), Answer { checksum, answer });
+        }
+
+        Ok(answers)
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::from("# day part checksum answer\n");
+        for ((day, part), answer) in &self.answers {
+            out.push_str(&format!(
+                "{} {} {} {}\n",
+                day,
+                part,
+                answer.checksum,
+                escape(&answer.answer)
+            ));
+        }
+        out
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, self.render())
+    }
+
+    pub fn get(&self, day: usize, part: usize) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    /// records the finished parts of a run as verified, returning how many there were
+    pub fn record(&mut self, run: &Run) -> usize {
+        let mut recorded = 0;
+        for (idx, part) in run.parts.iter().enumerate() {
+            if let Ok(answer) = &part.answer {
+                let answer = Answer {
+                    checksum: run.checksum.clone(),
+                    answer: answer.clone(),
+                };
+                self.answers.insert((run.day, idx + 1), answer);
+                recorded += 1;
+            }
+        }
+        recorded
+    }
+
+    /// compares each part of a run with its verified answer
+    pub fn check(&self, run: &Run) -> [Verdict; 2] {
+        [1, 2].map(|part| {
+            let Some(verified) = self.get(run.day, part) else {
+                return Verdict::Unchecked;
+            };
+            if verified.checksum != run.checksum {
+                return Verdict::InputChanged {
+                    recorded: verified.checksum.clone(),
+                };
+            }
+
+            match &run.parts[part - 1].answer {
+                Ok(answer) if *answer == verified.answer => Verdict::Verified,
+                Ok(_) => Verdict::Wrong {
+                    expected: verified.answer.clone(),
+                },
+                Err(_) => Verdict::Unchecked,
+            }
+        })
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                out.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                out.push('\\');
+                chars.next();
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,233 @@
+//! Solving a day against a whole directory of inputs
+//!
+//! Written by Trevor Leibert
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::cancel::CancelToken;
+use crate::params::Params;
+use crate::report::table_cell;
+use crate::runner::{format_duration, solve_part};
+use crate::solution::{Context, Day, Error, PartRun};
+
+/// how many of the most common answers the summary lists
+const MOST_COMMON: usize = 5;
+
+/// One input file's answers
+pub struct FileResult {
+    pub name: String,
+    /// why the file doesn't look like the day's input, see [`Day::check_shape`]
+    pub shape: Result<(), String>,
+    /// each part's answer, or why the file couldn't be read
+    pub parts: Result<[PartRun; 2], Error>,
+}
+
+/// solves every file in `dir` with the day, in name order. Each part is
+/// solved separately, so a panic on a malformed file only fails that part.
+pub fn run_dir(
+    solution: &dyn Day,
+    dir: &Path,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<Vec<FileResult>, Error> {
+    let read_dir = |source| Error::Input {
+        path: dir.to_path_buf(),
+        source,
+    };
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .map_err(read_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()
+        .map_err(read_dir)?;
+    paths.retain(|path| {
+        path.is_file()
+            && !path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'))
+    });
+    paths.sort();
+
+    let results = paths
+        .into_iter()
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(source) => {
+                    return FileResult {
+                        name,
+                        shape: Ok(()),
+                        parts: Err(Error::Input { path, source }),
+                    }
+                }
+            };
+
+            let solve = |part| {
+                let cancel = timeout.map_or_else(CancelToken::new, CancelToken::with_timeout);
+                let ctx = Context::new(params.clone()).with_cancel(cancel);
+                let start = Instant::now();
+                let answer = solve_part(solution, &input, part, &ctx);
+                PartRun {
+                    answer,
+                    time: start.elapsed(),
+                }
+            };
+
+            FileResult {
+                name,
+                shape: solution.check_shape(&input),
+                parts: Ok([solve(1), solve(2)]),
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// renders the answers as a Markdown table, followed by any problems
+/// with the files and a summary of each part's answers
+pub fn render(day: usize, results: &[FileResult]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# Day {} across {} inputs\n", day, results.len()).unwrap();
+    writeln!(out, "| File | Part 1 | Part 2 | Time |").unwrap();
+    writeln!(out, "| --- | --- | --- | ---: |").unwrap();
+
+    for result in results {
+        let (answers, time) = match &result.parts {
+            Ok(parts) => (
+                parts.each_ref().map(|part| match &part.answer {
+                    Ok(answer) => table_cell(answer),
+                    Err(Error::Unsolved) => "_unsolved_".to_string(),
+                    Err(Error::Cancelled { .. }) => "_timed out_".to_string(),
+                    Err(_) => "_failed_".to_string(),
+                }),
+                format_duration(parts.iter().map(|part| part.time).sum()),
+            ),
+            Err(_) => (["_unreadable_".to_string(), String::new()], String::new()),
+        };
+        writeln!(
+            out,
+            "| {} | {} | {} | {} |",
+            table_cell(&result.name),
+            answers[0],
+            answers[1],
+            time
+        )
+        .unwrap();
+    }
+
+    let problems = problems(results);
+    if !problems.is_empty() {
+        writeln!(out, "\n## Problems\n").unwrap();
+        for problem in problems {
+            writeln!(out, "- {}", problem).unwrap();
+        }
+    }
+
+    for part in 1..=2 {
+        writeln!(out, "\n## Part {}\n", part).unwrap();
+        let answers: Vec<&str> = results
+            .iter()
+            .filter_map(|result| result.parts.as_ref().ok())
+            .filter_map(|parts| parts[part - 1].answer.as_deref().ok())
+            .collect();
+        out.push_str(&summarise(&answers, results.len()));
+    }
+
+    out
+}
+
+/// every file that looked wrong, couldn't be read or failed to solve.
+/// Unsolved parts aren't a problem with the file, so they're left out.
+fn problems(results: &[FileResult]) -> Vec<String> {
+    let mut problems = Vec::new();
+    for result in results {
+        if let Err(shape) = &result.shape {
+            problems.push(format!(
+                "{}: doesn't look like this day's input, {}",
+                result.name, shape
+            ));
+        }
+
+        match &result.parts {
+            Ok(parts) => {
+                for (idx, part) in parts.iter().enumerate() {
+                    match &part.answer {
+                        Ok(_) | Err(Error::Unsolved) => (),
+                        Err(err) => {
+                            problems.push(format!("{}: part {} {}", result.name, idx + 1, err))
+                        }
+                    }
+                }
+            }
+            Err(err) => problems.push(format!("{}: {}", result.name, err)),
+        }
+    }
+    problems
+}
+
+/// how many files were solved, and how the answers are spread out
+fn summarise(answers: &[&str], files: usize) -> String {
+    let mut out = String::new();
+    writeln!(out, "Solved {} of {} inputs", answers.len(), files).unwrap();
+    if answers.is_empty() {
+        return out;
+    }
+
+    let mut numbers: Vec<i128> = answers
+        .iter()
+        .filter_map(|answer| answer.parse().ok())
+        .collect();
+    if numbers.len() == answers.len() {
+        numbers.sort_unstable();
+        let mean = numbers.iter().map(|n| *n as f64).sum::<f64>() / numbers.len() as f64;
+        writeln!(
+            out,
+            "\n| Min | Median | Mean | Max |\n| ---: | ---: | ---: | ---: |\n| {} | {} | {:.1} | {} |",
+            numbers[0],
+            median(&numbers),
+            mean,
+            numbers[numbers.len() - 1]
+        )
+        .unwrap();
+    }
+
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for answer in answers {
+        *counts.entry(answer).or_default() += 1;
+    }
+    let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+    counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+
+    writeln!(
+        out,
+        "\n{} distinct answers, most common first:\n",
+        counts.len()
+    )
+    .unwrap();
+    writeln!(out, "| Answer | Inputs |").unwrap();
+    writeln!(out, "| --- | ---: |").unwrap();
+    for (answer, count) in counts.iter().take(MOST_COMMON) {
+        writeln!(out, "| {} | {} |", table_cell(answer), count).unwrap();
+    }
+    out
+}
+
+/// the middle of some sorted numbers, averaging the two middle ones if there's an even count
+fn median(sorted: &[i128]) -> String {
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        sorted[mid].to_string()
+    } else {
+        let sum = sorted[mid - 1] + sorted[mid];
+        if sum % 2 == 0 {
+            (sum / 2).to_string()
+        } else {
+            format!("{:.1}", sum as f64 / 2.0)
+        }
+    }
+}
@@ -0,0 +1,213 @@
+//! Runner for all the Advent of Code solutions
+//!
+//! Written by Trevor Leibert
+
+use std::env;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::process;
+
+use advent_of_code_2021::batch;
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::report;
+use advent_of_code_2021::runner::{self, RunOptions};
+use advent_of_code_2021::scaffold;
+use advent_of_code_2021::serve::{Event, Server};
+use advent_of_code_2021::solution::Error;
+
+const USAGE: &str = "Usage:
+  aoc run <day> [options]                solve a day against inputs/input_<day>.txt
+  aoc report [--output <file>] [--timeout <duration>]
+                                         write a Markdown report of every day (default report.md)
+  aoc batch <day> <dir> [options]        solve a day against every file in a directory
+  aoc serve [--port <port>] [--timeout <duration>]
+                                         solve days over HTTP on localhost (default port 2021)
+  aoc new <day>                          scaffold the module, test and input for a new day
+  aoc help [<day>]                       show this message, or the parameters a day accepts
+
+Options for run and batch:
+  --param key=value       override one of the day's puzzle constants
+  --timeout <duration>    give up on the day after e.g. 30s, 500ms or 2m
+  --record                save the answers as verified for this input in inputs/answers.txt
+  --checkpoint-every <n>  save long simulations to checkpoints/ every n steps (days 6, 11, 13, 14)
+  --resume <file>         pick a run back up from a saved checkpoint
+  --example               solve the day's worked example from the puzzle instead of its input
+  --progress              show how far the slower solvers have got (days 7, 11 and 12)";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = args.first().map(String::as_str).unwrap_or("help");
+
+    let result = match command {
+        "run" => run(&args[1..]),
+        "report" => write_report(&args[1..]),
+        "batch" => run_batch(&args[1..]),
+        "serve" => serve(&args[1..]),
+        "new" => new_day(&args[1..]),
+        "help" | "--help" | "-h" => help(&args[1..]),
+        other => Err(CliError::Usage(format!("unknown command `{}`", other))),
+    };
+
+    match result {
+        Ok(()) => (),
+        Err(CliError::Usage(err)) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+        Err(CliError::Failed(err)) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+/// Why a command failed
+enum CliError {
+    /// the command line was wrong, so show how to use it
+    Usage(String),
+    /// the command itself failed
+    Failed(String),
+}
+
+impl From<Error> for CliError {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Params(_) | Error::UnknownDay(_) | Error::Usage(_) => {
+                CliError::Usage(err.to_string())
+            }
+            err => CliError::Failed(err.to_string()),
+        }
+    }
+}
+
+/// parses the day argument of a subcommand
+fn parse_day(arg: Option<&String>) -> Result<usize, CliError> {
+    let arg = arg.ok_or_else(|| CliError::Usage("missing day".to_string()))?;
+    arg.parse()
+        .map_err(|_| CliError::Usage(format!("`{}` is not a day number", arg)))
+}
+
+fn run(args: &[String]) -> Result<(), CliError> {
+    let day = parse_day(args.first())?;
+    let day_run = runner::run_day(day, &args[1..])?;
+    runner::print_run(&day_run);
+    if runner::was_cancelled(&day_run.run) {
+        return Err(CliError::Failed(format!("day {} timed out", day)));
+    }
+    if day_run.example && runner::any_wrong(&day_run) {
+        return Err(CliError::Failed(format!(
+            "day {} got the example wrong",
+            day
+        )));
+    }
+    Ok(())
+}
+
+fn run_batch(args: &[String]) -> Result<(), CliError> {
+    let day = parse_day(args.first())?;
+    let dir = args
+        .get(1)
+        .ok_or_else(|| CliError::Usage("missing the directory of inputs".to_string()))?;
+    let solution = runner::find_day(day)?;
+    let options = RunOptions::from_args(&args[2..])?;
+    reject_run_only(&options)?;
+    let params = Params::from_args(solution.params(), &options.params).map_err(Error::from)?;
+
+    // malformed files are reported in the table, so keep their panics off
+    // stderr, but only while the files are being solved
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = batch::run_dir(solution, Path::new(dir), &params, options.timeout);
+    panic::set_hook(previous);
+    let results = results?;
+    print!("{}", batch::render(day, &results));
+    Ok(())
+}
+
+fn write_report(args: &[String]) -> Result<(), CliError> {
+    let options = RunOptions::from_args(args)?;
+    reject_run_only(&options)?;
+    let output = only_flag(&options.params, "--output", "a file")?;
+    let output = output.unwrap_or("report.md");
+
+    let markdown = report::render(&report::run_all(options.timeout));
+    fs::write(output, markdown)
+        .map_err(|err| CliError::Failed(format!("couldn't write {}: {}", output, err)))?;
+    println!("Wrote report to {}", output);
+    Ok(())
+}
+
+fn serve(args: &[String]) -> Result<(), CliError> {
+    let options = RunOptions::from_args(args)?;
+    reject_run_only(&options)?;
+    let port = match only_flag(&options.params, "--port", "a port number")? {
+        Some(port) => port
+            .parse()
+            .map_err(|_| CliError::Usage(format!("`{}` is not a port number", port)))?,
+        None => 2021,
+    };
+
+    let failed = |err: std::io::Error| CliError::Failed(err.to_string());
+    let server = Server::bind(port, options.timeout)
+        .map_err(failed)?
+        .with_log(|event| match event {
+            Event::Answered { .. } => println!("{}", event),
+            Event::Failed(_) => eprintln!("{}", event),
+        });
+    println!(
+        "Listening on http://{}, POST inputs to /day/<n>/part/<p>",
+        server.local_addr().map_err(failed)?
+    );
+    server.run().map_err(failed)
+}
+
+/// fails if an option that only makes sense for `aoc run` was given
+fn reject_run_only(options: &RunOptions) -> Result<(), CliError> {
+    match options.run_only_flag() {
+        Some(flag) => Err(CliError::Usage(format!(
+            "`{}` only works with `aoc run`",
+            flag
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// the value of `flag`, given as `flag value` or `flag=value`, when it's the
+/// only argument left after the common options
+fn only_flag<'a>(args: &'a [String], flag: &str, needs: &str) -> Result<Option<&'a str>, CliError> {
+    let inline = |arg: &'a String| arg.strip_prefix(flag)?.strip_prefix('=');
+    match args {
+        [] => Ok(None),
+        [arg, value] if arg == flag => Ok(Some(value)),
+        [arg] if arg == flag => {
+            let message = format!("`{}` needs {}", flag, needs);
+            Err(CliError::Usage(message))
+        }
+        [arg] if inline(arg).is_some() => Ok(inline(arg)),
+        _ => Err(CliError::Usage(format!("unexpected arguments {:?}", args))),
+    }
+}
+
+fn new_day(args: &[String]) -> Result<(), CliError> {
+    let day = parse_day(args.first())?;
+    let created =
+        scaffold::new_day(Path::new("."), day).map_err(|err| CliError::Failed(err.to_string()))?;
+
+    for path in created {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn help(args: &[String]) -> Result<(), CliError> {
+    if args.is_empty() {
+        println!("{}", USAGE);
+        return Ok(());
+    }
+
+    let day = parse_day(args.first())?;
+    let solution = runner::find_day(day)?;
+    println!("{}", Params::usage(solution.params()));
+    Ok(())
+}
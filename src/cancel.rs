@@ -0,0 +1,69 @@
+//! Cooperative cancellation for long-running solvers
+//!
+//! Written by Trevor Leibert
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Shared flag telling solvers to stop, optionally tripped by a deadline.
+/// Solvers poll it through [`Context::check`](crate::solution::Context::check)
+/// in their loops; clones share the same flag.
+#[derive(Debug, Clone)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    started: Instant,
+    deadline: Option<Instant>,
+}
+
+impl Default for CancelToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancelToken {
+    /// a token that is only cancelled by calling [`cancel`](Self::cancel)
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started: Instant::now(),
+            deadline: None,
+        }
+    }
+
+    /// a token that cancels itself once `timeout` has passed
+    pub fn with_timeout(timeout: Duration) -> Self {
+        let started = Instant::now();
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            started,
+            deadline: started.checked_add(timeout),
+        }
+    }
+
+    /// tells everything holding this token to stop
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// true once the token was cancelled or its deadline passed
+    pub fn is_cancelled(&self) -> bool {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return true;
+        }
+
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.cancel();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// how long it's been since the token was created
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+}
@@ -0,0 +1,204 @@
+//! Saving and resuming long simulations
+//!
+//! Written by Trevor Leibert
+//!
+//! A checkpoint is a small text file: a header saying where the run was,
+//! then the simulation state as written by its [`Checkpoint`] impl.
+//!
+//! ```text
+//! aoc checkpoint
+//! day 11
+//! part 1
+//! kind octopode
+//! step 200
+//! total 1656
+//! state
+//! 5483143223
+//! ...
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+use crate::solution::Error;
+
+const MAGIC: &str = "aoc checkpoint";
+
+/// Simulation state that can be written to a checkpoint and read back
+pub trait Checkpoint: Sized {
+    /// names the kind of state, so a checkpoint can't be loaded as the wrong type
+    const KIND: &'static str;
+
+    /// writes out the full state as text
+    fn save(&self) -> String;
+
+    /// reads back what [`save`](Self::save) wrote
+    fn load(text: &str) -> Result<Self, ParseError>;
+}
+
+/// State picked up from a checkpoint, along with how far the run had got
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot<T> {
+    /// how many steps had been run
+    pub step: usize,
+    /// a running total the part keeps next to the state, like day 11's flashes
+    pub total: u64,
+    pub state: T,
+}
+
+/// A checkpoint file, with the state still as text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Saved {
+    pub day: usize,
+    pub part: usize,
+    pub kind: String,
+    pub step: usize,
+    pub total: u64,
+    pub state: String,
+}
+
+impl Saved {
+    pub fn render(&self) -> String {
+        format!(
+            "{}\nday {}\npart {}\nkind {}\nstep {}\ntotal {}\nstate\n{}\n",
+            MAGIC,
+            self.day,
+            self.part,
+            self.kind,
+            self.step,
+            self.total,
+            self.state.trim_end_matches('\n')
+        )
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        header(text, 1, MAGIC, "`aoc checkpoint`")?;
+        let day = header_number(text, 2, "day ", "`day <n>`")?;
+        let part = header_number(text, 3, "part ", "`part <n>`")?;
+        let kind = header(text, 4, "kind ", "`kind <name>`")?.to_string();
+        let step = header_number(text, 5, "step ", "`step <n>`")?;
+        let total = header_number(text, 6, "total ", "`total <n>`")?;
+        header(text, 7, "state", "`state`")?;
+
+        let state = text.lines().skip(7).collect::<Vec<_>>().join("\n");
+        Ok(Self {
+            day,
+            part,
+            kind,
+            step,
+            total,
+            state,
+        })
+    }
+
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        let text = fs::read_to_string(path).map_err(|err| {
+            Error::Checkpoint(format!("couldn't read {}: {}", path.display(), err))
+        })?;
+        Self::parse(&text)
+            .map_err(|err| Error::Checkpoint(format!("{} is malformed, {}", path.display(), err)))
+    }
+}
+
+/// the rest of header line `line`, which must start with `name`
+fn header<'a>(
+    text: &'a str,
+    line: usize,
+    name: &str,
+    expected: &'static str,
+) -> Result<&'a str, ParseError> {
+    text.lines()
+        .nth(line - 1)
+        .and_then(|value| value.strip_prefix(name))
+        .map(str::trim)
+        .ok_or(ParseError::BadLine { line, expected })
+}
+
+fn header_number<N: FromStr>(
+    text: &str,
+    line: usize,
+    name: &str,
+    expected: &'static str,
+) -> Result<N, ParseError> {
+    header(text, line, name, expected)?
+        .parse()
+        .map_err(|_| ParseError::BadLine { line, expected })
+}
+
+/// How a run saves checkpoints, and which one it picks up from
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoints {
+    /// save the state every this many steps
+    pub every: Option<usize>,
+    /// the directory checkpoints are saved in
+    pub dir: PathBuf,
+    /// a checkpoint to resume from
+    pub resume: Option<Saved>,
+}
+
+impl Checkpoints {
+    /// where the checkpoints of one part of a day are saved
+    pub fn path(&self, day: usize, part: usize) -> PathBuf {
+        self.dir.join(format!("day_{}_part_{}.txt", day, part))
+    }
+
+    /// writes a checkpoint, replacing the last one for the same part
+    pub fn save<T: Checkpoint>(
+        &self,
+        day: usize,
+        part: usize,
+        snapshot: &Snapshot<&T>,
+    ) -> Result<(), Error> {
+        let saved = Saved {
+            day,
+            part,
+            kind: T::KIND.to_string(),
+            step: snapshot.step,
+            total: snapshot.total,
+            state: snapshot.state.save(),
+        };
+
+        let path = self.path(day, part);
+        // write then rename, so a run killed mid-save leaves the last checkpoint intact
+        let partial = path.with_extension("partial");
+        fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&partial, saved.render()))
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|err| Error::Checkpoint(format!("couldn't save {}: {}", path.display(), err)))
+    }
+
+    /// the state to resume from, if the checkpoint is for this part
+    pub fn resume<T: Checkpoint>(
+        &self,
+        day: usize,
+        part: usize,
+    ) -> Result<Option<Snapshot<T>>, Error> {
+        let Some(saved) = self
+            .resume
+            .as_ref()
+            .filter(|saved| saved.day == day && saved.part == part)
+        else {
+            return Ok(None);
+        };
+
+        if saved.kind != T::KIND {
+            return Err(Error::Checkpoint(format!(
+                "the checkpoint holds {} state, but day {} part {} needs {}",
+                saved.kind,
+                day,
+                part,
+                T::KIND
+            )));
+        }
+
+        let state = T::load(&saved.state)
+            .map_err(|err| Error::Checkpoint(format!("the saved state is malformed, {}", err)))?;
+        Ok(Some(Snapshot {
+            step: saved.step,
+            total: saved.total,
+            state,
+        }))
+    }
+}
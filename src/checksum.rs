@@ -0,0 +1,19 @@
+//! Checksums for identifying puzzle inputs
+//!
+//! Written by Trevor Leibert
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// 64 bit FNV-1a hash of some data. Unlike `DefaultHasher` this is stable
+/// across Rust versions, so it's safe to write down and compare later.
+pub fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// the checksum of an input, formatted as hex
+pub fn checksum(data: &str) -> String {
+    format!("{:016x}", fnv1a(data.as_bytes()))
+}
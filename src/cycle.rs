@@ -0,0 +1,155 @@
+//! Finding where deterministic simulations start repeating
+//!
+//! Written by Trevor Leibert
+//!
+//! Once a simulation's state repeats, every step after that is known, so the
+//! state after any number of steps only costs as much as finding the cycle.
+//! That can still take a long time, so the search checks the [`Context`]
+//! for cancellation as it goes.
+
+use crate::solution::{Context, Error};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A simulation where each state decides the next
+pub trait Simulation: Clone + Eq + Hash {
+    /// moves on one step, returning what the step adds to a running total
+    /// like day 11's flashes, or 0 if nothing is counted
+    fn advance(&mut self) -> u64;
+}
+
+/// Where a simulation's states start repeating
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// the steps before the first state that repeats
+    pub start: u64,
+    /// the number of steps before a state comes round again
+    pub len: u64,
+}
+
+/// A state, with its hash so most comparisons don't touch the whole state
+struct Hashed<S> {
+    state: S,
+    hash: u64,
+}
+
+impl<S: Simulation> Hashed<S> {
+    fn new(state: S) -> Self {
+        Self {
+            hash: hash(&state),
+            state,
+        }
+    }
+
+    fn advance(&mut self) -> u64 {
+        let added = self.state.advance();
+        self.hash = hash(&self.state);
+        added
+    }
+
+    fn same(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.state == other.state
+    }
+}
+
+fn hash<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// How far [`search`] got
+enum Search<S> {
+    /// the step limit came before the cycle, with the state and total there
+    Reached(S, u64),
+    Found(Cycle),
+}
+
+/// finds the cycle the simulation falls into with Brent's algorithm, which
+/// only keeps two states around. Runs until cancelled if the states don't repeat.
+pub fn find_cycle<S: Simulation>(initial: &S, ctx: &Context) -> Result<Cycle, Error> {
+    match search(initial, u64::MAX, ctx)? {
+        Search::Found(cycle) => Ok(cycle),
+        Search::Reached(..) => unreachable!("stepped u64::MAX times without a repeat"),
+    }
+}
+
+/// the state after `steps` steps, and the total of what they added. Stops
+/// early if it gets there before finding the cycle, so it's no slower than
+/// stepping for small step counts.
+pub fn after<S: Simulation>(initial: &S, steps: u64, ctx: &Context) -> Result<(S, u64), Error> {
+    let cycle = match search(initial, steps, ctx)? {
+        Search::Reached(state, total) => return Ok((state, total)),
+        Search::Found(cycle) => cycle,
+    };
+
+    let mut state = initial.clone();
+    let mut total = run(&mut state, cycle.start, ctx)?;
+    let lap = run(&mut state, cycle.len, ctx)?;
+    let remaining = steps - cycle.start - cycle.len;
+    total += lap * (remaining / cycle.len + 1);
+    total += run(&mut state, remaining % cycle.len, ctx)?;
+    Ok((state, total))
+}
+
+/// a step is usually only a few instructions, so don't look at the clock every time
+fn check(ctx: &Context, step: u64, doing: &str) -> Result<(), Error> {
+    if step.is_multiple_of(1024) {
+        ctx.check(|| format!("{} after {} steps", doing, step))
+    } else {
+        Ok(())
+    }
+}
+
+/// steps the state `steps` times, returning the total added
+fn run<S: Simulation>(state: &mut S, steps: u64, ctx: &Context) -> Result<u64, Error> {
+    let mut total = 0;
+    for step in 0..steps {
+        check(ctx, step, "still replaying the cycle")?;
+        total += state.advance();
+    }
+    Ok(total)
+}
+
+/// runs Brent's algorithm, unless `limit` steps come first
+fn search<S: Simulation>(initial: &S, limit: u64, ctx: &Context) -> Result<Search<S>, Error> {
+    // find the cycle length: the tortoise waits at powers of two for the hare to lap it
+    let mut tortoise = Hashed::new(initial.clone());
+    let mut hare = Hashed::new(initial.clone());
+    let mut total = 0;
+    let mut power = 1;
+    let mut len = 0;
+    let mut steps = 0;
+    loop {
+        if steps == limit {
+            return Ok(Search::Reached(hare.state, total));
+        }
+        check(ctx, steps, "no repeated state")?;
+        total += hare.advance();
+        steps += 1;
+        len += 1;
+        if tortoise.same(&hare) {
+            break;
+        }
+        if len == power {
+            tortoise = Hashed::new(hare.state.clone());
+            power *= 2;
+            len = 0;
+        }
+    }
+
+    // find the start: walk two states `len` apart until they meet
+    let mut tortoise = Hashed::new(initial.clone());
+    let mut hare = initial.clone();
+    run(&mut hare, len, ctx)?;
+    let mut hare = Hashed::new(hare);
+    let mut start = 0;
+    while !tortoise.same(&hare) {
+        check(ctx, start, "still looking for where the cycle starts")?;
+        tortoise.advance();
+        hare.advance();
+        start += 1;
+    }
+
+    Ok(Search::Found(Cycle { start, len }))
+}
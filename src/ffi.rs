@@ -0,0 +1,119 @@
+//! C ABI for calling the solvers from other languages
+//!
+//! Written by Trevor Leibert
+//!
+//! `build.rs` generates the C header from this file, and `include/aoc.h` is
+//! a checked-in copy of it, so stick to the types the generator knows.
+
+use crate::params::Params;
+use crate::runner;
+use crate::solution::{Context, Error};
+use std::ffi::{c_char, CString};
+use std::{ptr, slice, str};
+
+/// Version of the C API, bumped whenever a signature or status code changes
+pub const AOC_ABI_VERSION: u32 = 1;
+
+/// The answer was written to `out`
+pub const AOC_OK: i32 = 0;
+/// No solution is registered for the day
+pub const AOC_UNKNOWN_DAY: i32 = 1;
+/// The part wasn't 1 or 2
+pub const AOC_UNKNOWN_PART: i32 = 2;
+/// The input wasn't valid UTF-8
+pub const AOC_INVALID_INPUT: i32 = 3;
+/// The part hasn't been solved yet
+pub const AOC_UNSOLVED: i32 = 4;
+/// The solver failed, usually because the input was malformed
+pub const AOC_FAILED: i32 = 5;
+/// The input pointer was null
+pub const AOC_NULL_POINTER: i32 = 6;
+
+/// Returns `AOC_ABI_VERSION` as compiled into the library
+#[no_mangle]
+pub extern "C" fn aoc_abi_version() -> u32 {
+    AOC_ABI_VERSION
+}
+
+/// Solves one part of a day against `input_len` bytes of UTF-8 input.
+/// Returns a status code. On `AOC_OK`, `*out` is the answer, otherwise
+/// it's a message saying what went wrong. Either way the caller owns the
+/// string and must release it with `aoc_string_free`. `out` may be null
+/// if the caller only wants the status.
+///
+/// # Safety
+/// `input` must point to `input_len` readable bytes, and `out` must be
+/// null or valid to write a pointer to.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u32,
+    part: u32,
+    input: *const u8,
+    input_len: usize,
+    out: *mut *mut c_char,
+) -> i32 {
+    let (status, message) = if input.is_null() {
+        (AOC_NULL_POINTER, "input was null".to_string())
+    } else {
+        let bytes = slice::from_raw_parts(input, input_len);
+        match str::from_utf8(bytes) {
+            Ok(input) => solve(day as usize, part as usize, input),
+            Err(err) => (AOC_INVALID_INPUT, format!("input isn't UTF-8: {}", err)),
+        }
+    };
+
+    if !out.is_null() {
+        *out = into_c_string(message);
+    }
+
+    status
+}
+
+/// Frees a string returned by this library. Null is ignored.
+///
+/// # Safety
+/// `s` must be null or a string from this library that hasn't been freed.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// runs the solver, turning panics from bad input into a status,
+/// since unwinding into C isn't allowed
+fn solve(day: usize, part: usize, input: &str) -> (i32, String) {
+    let day = match runner::find_day(day) {
+        Ok(day) => day,
+        Err(err) => return (AOC_UNKNOWN_DAY, err.to_string()),
+    };
+
+    let ctx = Context::new(Params::defaults(day.params()));
+    match runner::solve_part(day, input, part, &ctx) {
+        Ok(answer) => (AOC_OK, answer),
+        Err(err) => (status(&err), err.to_string()),
+    }
+}
+
+/// the status a failed solve is returned with
+pub fn status(err: &Error) -> i32 {
+    match err {
+        Error::UnknownDay(_) => AOC_UNKNOWN_DAY,
+        Error::UnknownPart(_) => AOC_UNKNOWN_PART,
+        // only a day fresh from `aoc new` is still unsolved, so tests/c/solve.c
+        // can't reach this one
+        Error::Unsolved => AOC_UNSOLVED,
+        _ => AOC_FAILED,
+    }
+}
+
+fn into_c_string(s: String) -> *mut c_char {
+    // answers never contain NUL, but don't hand back a truncated string if one does
+    match CString::new(s) {
+        Ok(s) => s.into_raw(),
+        Err(err) => {
+            let cleaned: Vec<u8> = err.into_vec().into_iter().filter(|b| *b != 0).collect();
+            CString::new(cleaned).map_or(ptr::null_mut(), CString::into_raw)
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Shared code for the Advent of Code 2021 solutions
+//!
+//! Written by Trevor Leibert
+
+pub mod answers;
+pub mod batch;
+pub mod cancel;
+pub mod checkpoint;
+pub mod checksum;
+pub mod cycle;
+pub mod ffi;
+pub mod params;
+pub mod parse;
+pub mod problems;
+pub mod progress;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod script;
+pub mod serve;
+pub mod shape;
+pub mod solution;
@@ -0,0 +1,308 @@
+//! Command line parameter overrides for the puzzle constants
+//!
+//! Written by Trevor Leibert
+//!
+//! Each day declares the constants it uses (number of days, steps, grid
+//! size...) as a list of [`ParamSpec`]s, and the values can then be
+//! overridden on the command line with `--param key=value`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// The type a parameter's value has to parse as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    Usize,
+    Int,
+    Bool,
+}
+
+impl Display for ParamKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ParamKind::Usize => "usize",
+            ParamKind::Int => "int",
+            ParamKind::Bool => "bool",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A parsed parameter value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamValue {
+    Usize(usize),
+    Int(i64),
+    Bool(bool),
+}
+
+impl Display for ParamValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamValue::Usize(val) => write!(f, "{}", val),
+            ParamValue::Int(val) => write!(f, "{}", val),
+            ParamValue::Bool(val) => write!(f, "{}", val),
+        }
+    }
+}
+
+/// Declaration of a single tunable puzzle constant
+#[derive(Debug, Clone, Copy)]
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    pub default: &'static str,
+    pub help: &'static str,
+    /// the most a `usize` parameter can be set to over `aoc serve`, for the
+    /// ones that size an allocation, since running out of memory can't be caught
+    pub serve_max: Option<usize>,
+}
+
+impl ParamSpec {
+    pub const fn new(
+        name: &'static str,
+        kind: ParamKind,
+        default: &'static str,
+        help: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            kind,
+            default,
+            help,
+            serve_max: None,
+        }
+    }
+
+    /// caps the value requests to `aoc serve` can set, see [`ParamSpec::serve_max`]
+    pub const fn with_serve_max(mut self, max: usize) -> Self {
+        self.serve_max = Some(max);
+        self
+    }
+
+    /// parses a value for this parameter according to its kind
+    fn parse(&self, value: &str) -> Result<ParamValue, ParamError> {
+        let parsed = match self.kind {
+            ParamKind::Usize => value.parse().ok().map(ParamValue::Usize),
+            ParamKind::Int => value.parse().ok().map(ParamValue::Int),
+            ParamKind::Bool => value.parse().ok().map(ParamValue::Bool),
+        };
+
+        parsed.ok_or_else(|| ParamError::InvalidValue {
+            name: self.name.to_string(),
+            value: value.to_string(),
+            kind: self.kind,
+        })
+    }
+}
+
+/// Everything that can go wrong reading parameter overrides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParamError {
+    /// `--param` was given something that isn't `key=value`
+    Malformed(String),
+    /// `--param` was the last argument
+    MissingValue,
+    /// the day doesn't declare a parameter with this name
+    Unknown {
+        name: String,
+        suggestion: Option<&'static str>,
+        known: Vec<&'static str>,
+    },
+    /// the value doesn't parse as the parameter's type
+    InvalidValue {
+        name: String,
+        value: String,
+        kind: ParamKind,
+    },
+    /// an argument that isn't a parameter override
+    UnexpectedArgument(String),
+}
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamError::Malformed(arg) => {
+                write!(f, "expected `--param key=value`, got `{}`", arg)
+            }
+            ParamError::MissingValue => write!(f, "`--param` needs a `key=value` after it"),
+            ParamError::Unknown {
+                name,
+                suggestion,
+                known,
+            } => {
+                write!(f, "unknown parameter `{}`", name)?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean `{}`?", suggestion)?;
+                }
+                if known.is_empty() {
+                    write!(f, " (this day has no parameters)")
+                } else {
+                    write!(f, " (known parameters: {})", known.join(", "))
+                }
+            }
+            ParamError::InvalidValue { name, value, kind } => write!(
+                f,
+                "parameter `{}` expects a value of type {}, got `{}`",
+                name, kind, value
+            ),
+            ParamError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{}`", arg),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// The resolved values of a day's parameters
+#[derive(Debug, Clone)]
+pub struct Params {
+    specs: &'static [ParamSpec],
+    values: HashMap<&'static str, ParamValue>,
+}
+
+impl Params {
+    /// creates a set of parameters holding every declared default
+    pub fn defaults(specs: &'static [ParamSpec]) -> Self {
+        let values = specs
+            .iter()
+            .map(|spec| {
+                let value = spec
+                    .parse(spec.default)
+                    .unwrap_or_else(|err| panic!("bad default for parameter: {}", err));
+                (spec.name, value)
+            })
+            .collect();
+
+        Self { specs, values }
+    }
+
+    /// parses `--param key=value` (or `--param=key=value`) arguments
+    /// on top of the declared defaults
+    pub fn from_args<I, S>(specs: &'static [ParamSpec], args: I) -> Result<Self, ParamError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut params = Self::defaults(specs);
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            if arg == "--param" {
+                let pair = args.next().ok_or(ParamError::MissingValue)?;
+                params.set(pair.as_ref())?;
+            } else if let Some(pair) = arg.strip_prefix("--param=") {
+                params.set(pair)?;
+            } else {
+                return Err(ParamError::UnexpectedArgument(arg.to_string()));
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// describes the parameters a day accepts
+    pub fn usage(specs: &[ParamSpec]) -> String {
+        if specs.is_empty() {
+            return "This day takes no parameters.".to_string();
+        }
+
+        let width = specs.iter().map(|spec| spec.name.len()).max().unwrap();
+        let lines: Vec<String> = specs
+            .iter()
+            .map(|spec| {
+                format!(
+                    "  {:width$}  {} (default {}, {})",
+                    spec.name,
+                    spec.help,
+                    spec.default,
+                    spec.kind,
+                    width = width
+                )
+            })
+            .collect();
+
+        format!("Parameters (--param key=value):\n{}", lines.join("\n"))
+    }
+
+    /// applies a single `key=value` override
+    pub fn set(&mut self, pair: &str) -> Result<(), ParamError> {
+        let (name, value) = pair
+            .split_once('=')
+            .ok_or_else(|| ParamError::Malformed(pair.to_string()))?;
+        let name = name.trim();
+
+        let spec = self
+            .specs
+            .iter()
+            .find(|spec| spec.name == name)
+            .ok_or_else(|| ParamError::Unknown {
+                name: name.to_string(),
+                suggestion: closest_name(self.specs, name),
+                known: self.specs.iter().map(|spec| spec.name).collect(),
+            })?;
+
+        let value = spec.parse(value.trim())?;
+        self.values.insert(spec.name, value);
+        Ok(())
+    }
+
+    /// gets a parameter's value
+    pub fn get(&self, name: &str) -> ParamValue {
+        *self
+            .values
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter `{}` was never declared", name))
+    }
+
+    /// gets a `usize` parameter's value
+    pub fn usize(&self, name: &str) -> usize {
+        match self.get(name) {
+            ParamValue::Usize(val) => val,
+            other => panic!("parameter `{}` is not a usize: {}", name, other),
+        }
+    }
+
+    /// gets an `int` parameter's value
+    pub fn int(&self, name: &str) -> i64 {
+        match self.get(name) {
+            ParamValue::Int(val) => val,
+            other => panic!("parameter `{}` is not an int: {}", name, other),
+        }
+    }
+
+    /// gets a `bool` parameter's value
+    pub fn bool(&self, name: &str) -> bool {
+        match self.get(name) {
+            ParamValue::Bool(val) => val,
+            other => panic!("parameter `{}` is not a bool: {}", name, other),
+        }
+    }
+}
+
+/// finds the declared name closest to a misspelled one, if any is close enough
+fn closest_name(specs: &[ParamSpec], name: &str) -> Option<&'static str> {
+    specs
+        .iter()
+        .map(|spec| (edit_distance(spec.name, name), spec.name))
+        .filter(|(dist, _)| *dist <= 2)
+        .min()
+        .map(|(_, name)| name)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + (ca != *cb) as usize;
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+
+    prev[b.len()]
+}
@@ -0,0 +1,121 @@
+//! Errors and helpers for parsing the puzzle types
+//!
+//! Written by Trevor Leibert
+
+use std::fmt::Display;
+
+/// Why some text couldn't be parsed into one of the puzzle types.
+/// Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// there was nothing to parse
+    Empty,
+    /// a character that doesn't belong
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// a line that isn't laid out as expected
+    BadLine { line: usize, expected: &'static str },
+    /// a word or number that isn't one of the ones allowed there
+    BadToken {
+        line: usize,
+        column: usize,
+        found: String,
+        expected: &'static str,
+    },
+    /// a row of a grid that isn't as wide as the first
+    Ragged {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// a block that has the wrong number of rows or columns
+    WrongSize {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "there's nothing to parse"),
+            ParseError::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "line {}, column {}: unexpected `{}`",
+                line, column, found
+            ),
+            ParseError::BadLine { line, expected } => {
+                write!(f, "line {} isn't {}", line, expected)
+            }
+            ParseError::BadToken {
+                line,
+                column,
+                found,
+                expected,
+            } => write!(
+                f,
+                "line {}, column {}: expected {}, found `{}`",
+                line, column, expected, found
+            ),
+            ParseError::Ragged {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "line {} is {} wide, but the first line is {}",
+                line, width, expected
+            ),
+            ParseError::WrongSize { expected, found } => write!(
+                f,
+                "expected {}x{}, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// parses a rectangular grid of the digits 0-9, row by row
+pub fn digit_grid(s: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut grid: Vec<Vec<u8>> = Vec::new();
+    for (y, line) in s.lines().map(str::trim_end).enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(x, c)| {
+                c.to_digit(10)
+                    .map(|digit| digit as u8)
+                    .ok_or(ParseError::UnexpectedChar {
+                        line: y + 1,
+                        column: x + 1,
+                        found: c,
+                    })
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+
+        if let Some(first) = grid.first() {
+            if row.len() != first.len() {
+                return Err(ParseError::Ragged {
+                    line: y + 1,
+                    width: row.len(),
+                    expected: first.len(),
+                });
+            }
+        }
+        grid.push(row);
+    }
+
+    match grid.first() {
+        Some(row) if !row.is_empty() => Ok(grid),
+        _ => Err(ParseError::Empty),
+    }
+}
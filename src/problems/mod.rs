@@ -0,0 +1,43 @@
+//! Every day's solution, registered for the runner
+//!
+//! Written by Trevor Leibert
+
+use crate::solution::Day;
+
+pub mod problem_1;
+pub mod problem_10;
+pub mod problem_11;
+pub mod problem_12;
+pub mod problem_13;
+pub mod problem_14;
+pub mod problem_2;
+pub mod problem_3;
+pub mod problem_4;
+pub mod problem_5;
+pub mod problem_6;
+pub mod problem_7;
+pub mod problem_8;
+pub mod problem_9;
+
+/// all the solved days, in order
+pub const DAYS: &[&dyn Day] = &[
+    &problem_1::Problem1,
+    &problem_2::Problem2,
+    &problem_3::Problem3,
+    &problem_4::Problem4,
+    &problem_5::Problem5,
+    &problem_6::Problem6,
+    &problem_7::Problem7,
+    &problem_8::Problem8,
+    &problem_9::Problem9,
+    &problem_10::Problem10,
+    &problem_11::Problem11,
+    &problem_12::Problem12,
+    &problem_13::Problem13,
+    &problem_14::Problem14,
+];
+
+/// finds the solution for a day
+pub fn get(day: usize) -> Option<&'static dyn Day> {
+    DAYS.iter().copied().find(|solution| solution.day() == day)
+}
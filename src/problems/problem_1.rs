@@ -0,0 +1,434 @@
+//! Advent of code problem 1
+//!
+//! Written by Trevor Leibert
+
+use crate::params::{ParamKind, ParamSpec};
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Display;
+use std::io::{self, prelude::*};
+
+pub struct Problem1;
+
+impl Solution for Problem1 {
+    const DAY: usize = 1;
+
+    const PARAMS: &'static [ParamSpec] = &[ParamSpec::new(
+        "window",
+        ParamKind::Usize,
+        "3",
+        "number of depths summed in each sliding window for part 2",
+    )
+    .with_serve_max(1_000_000)];
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_1.txt"),
+        params: &[],
+        answers: [Some("7"), Some("5")],
+    });
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a depth", |line| line.parse::<i64>().is_ok())
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(count_depth_increases::<1>(input.as_bytes())
+            .unwrap()
+            .to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let size = ctx.params.usize("window");
+        Ok(count_depth_increases_window(input.as_bytes(), size)
+            .unwrap()
+            .to_string())
+    }
+}
+
+/// counts how often the sum of a sliding window of `N` depths increases,
+/// see [`count_depth_increases_window`]. The puzzle's two parts are `N = 1`
+/// and `N = 3`.
+pub fn count_depth_increases<const N: usize>(reader: impl BufRead) -> io::Result<usize> {
+    count_depth_increases_window(reader, N)
+}
+
+/// counts how often the sum of a sliding window of depths increases,
+/// reading one line at a time. Inputs with no more than `size` depths have
+/// no two windows to compare, so they count 0. Blank lines are skipped, and
+/// a line that isn't a depth fails with [`io::ErrorKind::InvalidData`].
+pub fn count_depth_increases_window(reader: impl BufRead, size: usize) -> io::Result<usize> {
+    let mut error = None;
+    let depths = reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map_while(
+            |(idx, line)| match line.and_then(|line| parse_depth(&line, idx)) {
+                Ok(depth) => Some(depth),
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            },
+        );
+    let count = depths
+        .sonar_windows(size)
+        .filter(|window| window.change == Some(Change::Increase))
+        .count();
+
+    match error {
+        Some(err) => Err(err),
+        None => Ok(count),
+    }
+}
+
+/// parses the depth on the line at `idx`, counting from 0
+fn parse_depth(line: &str, idx: usize) -> io::Result<i64> {
+    line.trim().parse().map_err(|_| {
+        let err = ParseError::BadLine {
+            line: idx + 1,
+            expected: "a depth",
+        };
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    })
+}
+
+/// How a window's sum compares with the window before
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Increase,
+    Decrease,
+    Same,
+}
+
+/// One sliding window of depths
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    pub sum: i64,
+    /// `None` for the first window, which has nothing to compare with
+    pub change: Option<Change>,
+}
+
+/// Turns depths into sliding windows of them, see [`Sonar::sonar_windows`]
+pub struct SonarWindows<I> {
+    depths: I,
+    size: usize,
+    /// the depths in the current window, oldest first
+    window: VecDeque<i64>,
+    sum: i64,
+}
+
+impl<I: Iterator<Item = i64>> Iterator for SonarWindows<I> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        // fill the first window
+        while self.window.len() < self.size {
+            let depth = self.depths.next()?;
+            self.window.push_back(depth);
+            self.sum += depth;
+            if self.window.len() == self.size {
+                return Some(Window {
+                    sum: self.sum,
+                    change: None,
+                });
+            }
+        }
+
+        let depth = self.depths.next()?;
+        self.window.push_back(depth);
+        let dropped = self.window.pop_front().unwrap();
+        self.sum += depth - dropped;
+
+        // neighbouring windows share all but their first and last depths,
+        // so comparing those two is the same as comparing the sums
+        let change = match depth.cmp(&dropped) {
+            Ordering::Greater => Change::Increase,
+            Ordering::Less => Change::Decrease,
+            Ordering::Equal => Change::Same,
+        };
+        Some(Window {
+            sum: self.sum,
+            change: Some(change),
+        })
+    }
+}
+
+/// Sliding windows over any stream of depths
+pub trait Sonar: Iterator<Item = i64> + Sized {
+    /// lazily yields the sum of each window of `size` depths, and whether it
+    /// went up or down from the last one. A window of 0 depths never changes.
+    fn sonar_windows(self, size: usize) -> SonarWindows<Self> {
+        SonarWindows {
+            depths: self,
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            sum: 0,
+        }
+    }
+}
+
+impl<I: Iterator<Item = i64>> Sonar for I {}
+
+/// Settings for a [`SweepReport`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SweepOptions {
+    /// how many depths each window sums, 1 to look at the raw depths
+    pub window: usize,
+    /// how wide each histogram bucket of deltas is, at least 1
+    pub bucket_width: i64,
+    /// deltas more than this many standard deviations from the mean are outliers
+    pub outlier_deviations: f64,
+}
+
+impl Default for SweepOptions {
+    fn default() -> Self {
+        Self {
+            window: 1,
+            bucket_width: 10,
+            outlier_deviations: 3.0,
+        }
+    }
+}
+
+/// A change from one window to the next. `line` is the last line of the
+/// later window, counting from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub line: usize,
+    pub delta: i64,
+}
+
+/// A run of windows that kept going the same way. The lines run from the
+/// first line of its first window to the last line of its last window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub first_line: usize,
+    pub last_line: usize,
+    /// how many changes in a row went the same way
+    pub steps: usize,
+}
+
+/// How many deltas fell in `low..low + width`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub low: i64,
+    pub count: usize,
+}
+
+/// What a sonar sweep looked like, beyond how often it got deeper
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepReport {
+    pub options: SweepOptions,
+    pub windows: usize,
+    pub longest_increase: Option<Streak>,
+    pub longest_decrease: Option<Streak>,
+    pub max_rise: Option<Step>,
+    pub max_drop: Option<Step>,
+    pub mean_delta: f64,
+    pub std_dev: f64,
+    /// the deltas, bucketed from the lowest up. Empty buckets in between are left out.
+    pub histogram: Vec<Bucket>,
+    /// the steps whose delta is unusually far from the mean, in order
+    pub outliers: Vec<Step>,
+}
+
+impl SweepReport {
+    /// sweeps the depths with the sliding windows of [`Sonar::sonar_windows`],
+    /// failing if the buckets aren't at least 1 wide
+    pub fn new(depths: impl Iterator<Item = i64>, options: SweepOptions) -> Result<Self, Error> {
+        if options.bucket_width <= 0 {
+            return Err(Error::Usage(format!(
+                "buckets must be at least 1 wide, not {}",
+                options.bucket_width
+            )));
+        }
+
+        let mut windows = 0;
+        let mut steps = Vec::new();
+        let mut increase = StreakTracker::new(options.window);
+        let mut decrease = StreakTracker::new(options.window);
+        let mut prev_sum = 0;
+
+        for (idx, window) in depths.sonar_windows(options.window).enumerate() {
+            windows += 1;
+            if let Some(change) = window.change {
+                steps.push(Step {
+                    line: idx + options.window,
+                    delta: window.sum - prev_sum,
+                });
+                increase.push(idx, change == Change::Increase);
+                decrease.push(idx, change == Change::Decrease);
+            }
+            prev_sum = window.sum;
+        }
+
+        let count = steps.len() as f64;
+        let (mean_delta, std_dev) = if steps.is_empty() {
+            (0.0, 0.0)
+        } else {
+            let mean = steps.iter().map(|step| step.delta as f64).sum::<f64>() / count;
+            let variance = steps
+                .iter()
+                .map(|step| (step.delta as f64 - mean).powi(2))
+                .sum::<f64>()
+                / count;
+            (mean, variance.sqrt())
+        };
+
+        let mut buckets: BTreeMap<i64, usize> = BTreeMap::new();
+        for step in &steps {
+            *buckets
+                .entry(step.delta.div_euclid(options.bucket_width) * options.bucket_width)
+                .or_default() += 1;
+        }
+
+        let outliers = steps
+            .iter()
+            .filter(|step| {
+                std_dev > 0.0
+                    && (step.delta as f64 - mean_delta).abs() > options.outlier_deviations * std_dev
+            })
+            .copied()
+            .collect();
+
+        Ok(Self {
+            options,
+            windows,
+            longest_increase: increase.longest,
+            longest_decrease: decrease.longest,
+            // the first of any ties, so reports point at the earliest one
+            max_rise: steps.iter().filter(|step| step.delta > 0).fold(
+                None,
+                |best: Option<Step>, step| match best {
+                    Some(best) if best.delta >= step.delta => Some(best),
+                    _ => Some(*step),
+                },
+            ),
+            max_drop: steps.iter().filter(|step| step.delta < 0).fold(
+                None,
+                |best: Option<Step>, step| match best {
+                    Some(best) if best.delta <= step.delta => Some(best),
+                    _ => Some(*step),
+                },
+            ),
+            mean_delta,
+            std_dev,
+            histogram: buckets
+                .into_iter()
+                .map(|(low, count)| Bucket { low, count })
+                .collect(),
+            outliers,
+        })
+    }
+}
+
+/// Keeps track of the longest run of windows matching some change
+struct StreakTracker {
+    size: usize,
+    /// the window the current run started changing from, and its length
+    current: Option<(usize, usize)>,
+    longest: Option<Streak>,
+}
+
+impl StreakTracker {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            current: None,
+            longest: None,
+        }
+    }
+
+    /// window `idx` changed from the one before, `matches` says if it went the tracked way
+    fn push(&mut self, idx: usize, matches: bool) {
+        if !matches {
+            self.current = None;
+            return;
+        }
+
+        let (start, steps) = match self.current {
+            Some((start, steps)) => (start, steps + 1),
+            None => (idx - 1, 1),
+        };
+        self.current = Some((start, steps));
+        if self.longest.is_none_or(|longest| steps > longest.steps) {
+            self.longest = Some(Streak {
+                first_line: start + 1,
+                last_line: idx + self.size,
+                steps,
+            });
+        }
+    }
+}
+
+impl Display for SweepReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let streak = |streak: Option<Streak>| match streak {
+            Some(streak) => format!(
+                "{} steps, lines {} to {}",
+                streak.steps, streak.first_line, streak.last_line
+            ),
+            None => "none".to_string(),
+        };
+        let step = |step: Option<Step>| match step {
+            Some(step) => format!("{:+} at line {}", step.delta, step.line),
+            None => "none".to_string(),
+        };
+
+        writeln!(
+            f,
+            "Sonar sweep of {} windows of {}",
+            self.windows, self.options.window
+        )?;
+        writeln!(f, "Longest increase: {}", streak(self.longest_increase))?;
+        writeln!(f, "Longest decrease: {}", streak(self.longest_decrease))?;
+        writeln!(f, "Biggest rise: {}", step(self.max_rise))?;
+        writeln!(f, "Biggest drop: {}", step(self.max_drop))?;
+        writeln!(
+            f,
+            "Deltas: mean {:.2}, standard deviation {:.2}",
+            self.mean_delta, self.std_dev
+        )?;
+
+        writeln!(f, "Histogram:")?;
+        let most = self.histogram.iter().map(|bucket| bucket.count).max();
+        for bucket in &self.histogram {
+            // bars are scaled so the fullest bucket is 40 wide
+            let bar = (bucket.count * 40).div_ceil(most.unwrap());
+            writeln!(
+                f,
+                "  {:>6} to {:>6}  {:>5} {}",
+                bucket.low,
+                bucket.low + self.options.bucket_width - 1,
+                bucket.count,
+                "#".repeat(bar)
+            )?;
+        }
+
+        write!(
+            f,
+            "Outliers beyond {} standard deviations: ",
+            self.options.outlier_deviations
+        )?;
+        if self.outliers.is_empty() {
+            writeln!(f, "none")
+        } else {
+            let outliers: Vec<String> = self
+                .outliers
+                .iter()
+                .map(|outlier| step(Some(*outlier)))
+                .collect();
+            writeln!(f, "{}", outliers.join(", "))
+        }
+    }
+}
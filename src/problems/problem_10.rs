@@ -0,0 +1,142 @@
+//! Advent of Code problem 10
+//!
+//! Written by Trevor Leibert
+
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::collections::HashMap;
+use std::io::{self, BufRead, ErrorKind};
+
+pub struct Problem10;
+
+impl Solution for Problem10 {
+    const DAY: usize = 10;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_10.txt"),
+        params: &[],
+        answers: [Some("26397"), Some("288957")],
+    });
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a line of brackets", |line| {
+            line.chars().all(|c| "()[]{}<>".contains(c))
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let err_point = init_err_point_map();
+        let error_score = calculate_error_score(input.as_bytes(), &err_point).unwrap();
+        Ok(error_score.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let auto_point = init_auto_point_map();
+        let mut auto_score = auto_complete_score(input.as_bytes(), &auto_point)
+            .collect::<io::Result<Vec<_>>>()
+            .unwrap();
+        auto_score.sort();
+
+        Ok(auto_score[auto_score.len() / 2].to_string())
+    }
+}
+
+pub fn init_err_point_map() -> HashMap<char, usize> {
+    let mut err_point = HashMap::new();
+    err_point.insert(')', 3);
+    err_point.insert(']', 57);
+    err_point.insert('}', 1197);
+    err_point.insert('>', 25137);
+    err_point
+}
+
+pub fn init_auto_point_map() -> HashMap<char, usize> {
+    let mut err_point = HashMap::new();
+    err_point.insert(')', 1);
+    err_point.insert(']', 2);
+    err_point.insert('}', 3);
+    err_point.insert('>', 4);
+    err_point
+}
+
+/// a character that isn't a bracket, at 0-based `idx` and `column`
+fn unexpected_char(idx: usize, column: usize, found: char) -> io::Error {
+    let err = ParseError::UnexpectedChar {
+        line: idx + 1,
+        column: column + 1,
+        found,
+    };
+    io::Error::new(ErrorKind::InvalidData, err)
+}
+
+/// sums the error score of every corrupted line, reading one line at a time.
+/// A character that isn't a bracket fails with [`ErrorKind::InvalidData`].
+pub fn calculate_error_score(
+    reader: impl BufRead,
+    err_point: &HashMap<char, usize>,
+) -> io::Result<usize> {
+    reader
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let line = line?;
+            let mut stack = Vec::new();
+            for (column, char) in line.chars().enumerate() {
+                match char {
+                    '(' => stack.push(')'),
+                    '[' => stack.push(']'),
+                    '{' => stack.push('}'),
+                    '<' => stack.push('>'),
+                    c if err_point.contains_key(&c) => {
+                        if stack.pop() != Some(c) {
+                            return Ok(*err_point.get(&c).unwrap());
+                        }
+                    }
+                    _ => return Err(unexpected_char(idx, column, char)),
+                }
+            }
+            Ok(0)
+        })
+        .sum()
+}
+
+/// lazily scores the completion of every incomplete line, reading one line
+/// at a time. Corrupted lines are skipped, and a character that isn't a
+/// bracket fails with [`ErrorKind::InvalidData`].
+pub fn auto_complete_score<'a>(
+    reader: impl BufRead + 'a,
+    auto_point: &'a HashMap<char, usize>,
+) -> impl Iterator<Item = io::Result<usize>> + 'a {
+    reader.lines().enumerate().filter_map(move |(idx, line)| {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => return Some(Err(err)),
+        };
+        let mut stack = Vec::new();
+        for (column, char) in line.chars().enumerate() {
+            match char {
+                '(' => stack.push(')'),
+                '[' => stack.push(']'),
+                '{' => stack.push('}'),
+                '<' => stack.push('>'),
+                c if auto_point.contains_key(&c) => {
+                    if stack.pop() != Some(c) {
+                        return None;
+                    }
+                }
+                _ => return Some(Err(unexpected_char(idx, column, char))),
+            }
+        }
+
+        Some(Ok(stack.into_iter().rev().fold(0, |total, char| {
+            total * 5 + auto_point.get(&char).unwrap()
+        })))
+    })
+}
@@ -0,0 +1,230 @@
+//! Advent of Code problem 11
+//!
+//! Written by Trevor Leibert
+//! Yes I know Octopode is the wrong word, but it's more fun to write.
+
+use crate::checkpoint::Checkpoint;
+use crate::cycle::{self, Simulation};
+use crate::params::{ParamKind, ParamSpec};
+use crate::parse::{self, ParseError};
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::collections::VecDeque;
+use std::str::FromStr;
+
+pub struct Problem11;
+
+impl Solution for Problem11 {
+    const DAY: usize = 11;
+
+    const PARAMS: &'static [ParamSpec] = &[ParamSpec::new(
+        "steps",
+        ParamKind::Usize,
+        "100",
+        "number of steps to count flashes over",
+    )];
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_11.txt"),
+        params: &[],
+        answers: [Some("1656"), Some("195")],
+    });
+
+    type Input = Octopode;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        Octopode::new(input)
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        match shape::digit_grid(input)? {
+            (10, 10) => Ok(()),
+            (width, height) => Err(format!(
+                "the grid is {}x{}, but there are always 10x10 octopodes",
+                width, height
+            )),
+        }
+    }
+
+    fn part_1(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let steps = ctx.params.usize("steps");
+        let (mut octopodes, start, mut flashes) = match ctx.resume_up_to::<Octopode>(steps)? {
+            Some(snapshot) => (snapshot.state, snapshot.step, snapshot.total as usize),
+            // without checkpoints to save, skip ahead once the flashes start repeating
+            None if ctx.checkpoints.every.is_none() => {
+                return Ok(cycle::after(input, steps as u64, ctx)?.1.to_string());
+            }
+            None => (input.clone(), 0, 0),
+        };
+        for step in start..steps {
+            ctx.check(|| {
+                format!(
+                    "ran {} of {} steps, {} flashes so far",
+                    step, steps, flashes
+                )
+            })?;
+            flashes += octopodes.step();
+            ctx.checkpoint(step + 1, flashes as u64, &octopodes)?;
+        }
+
+        Ok(flashes.to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let (mut octopodes, mut step) = match ctx.resume::<Octopode>()? {
+            Some(snapshot) => (snapshot.state, snapshot.step),
+            None => (input.clone(), 0),
+        };
+        let target_flashes = octopodes.len();
+
+        loop {
+            ctx.check(|| format!("ran {} steps without a synchronized flash", step))?;
+            step += 1;
+            let flashes = octopodes.step();
+            ctx.progress(step as u64, None, "steps");
+            if flashes == target_flashes {
+                break;
+            }
+            ctx.checkpoint(step, 0, &octopodes)?;
+        }
+
+        Ok(step.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Octopode {
+    field: Vec<Vec<u8>>,
+}
+
+impl FromStr for Octopode {
+    type Err = ParseError;
+
+    /// parses a rectangular grid of energy levels from 0 to 9
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            field: parse::digit_grid(s)?,
+        })
+    }
+}
+
+impl Checkpoint for Octopode {
+    const KIND: &'static str = "octopode";
+
+    /// the energy levels, as they're laid out in the puzzle input
+    fn save(&self) -> String {
+        self.field
+            .iter()
+            .map(|row| row.iter().map(|level| level.to_string()).collect())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn load(text: &str) -> Result<Self, ParseError> {
+        text.parse()
+    }
+}
+
+/// once the octopodes synchronize they flash together every 10 steps,
+/// so the flashes after any number of steps are quick to find
+impl Simulation for Octopode {
+    fn advance(&mut self) -> u64 {
+        self.step() as u64
+    }
+}
+
+impl Octopode {
+    /// panics if the state isn't a grid of digits, see the [`FromStr`] impl
+    pub fn new(initial_state: &str) -> Self {
+        initial_state.parse().unwrap()
+    }
+
+    /// progresses the state of the simulation by one time step,
+    /// returning the number of octopodes that have flashed
+    pub fn step(&mut self) -> usize {
+        let mut to_flash = VecDeque::new();
+        for (y, row) in self.field.iter_mut().enumerate() {
+            for (x, num) in row.iter_mut().enumerate() {
+                *num += 1;
+                if *num == 10 {
+                    to_flash.push_back((x, y));
+                }
+            }
+        }
+
+        let mut flashes = 0;
+
+        while !to_flash.is_empty() {
+            let (x, y) = to_flash.pop_front().unwrap();
+            self.flash(x, y, &mut to_flash);
+            flashes += 1;
+        }
+
+        for row in &mut self.field {
+            for num in row {
+                if *num > 9 {
+                    *num = 0;
+                }
+            }
+        }
+
+        flashes
+    }
+
+    /// increment surrounding octopodes
+    fn flash(&mut self, x: usize, y: usize, to_flash: &mut VecDeque<(usize, usize)>) {
+        // increment above if in bounds, with diagonals if they're in bounds
+        if y > 0 {
+            self.inc(x, y - 1, to_flash);
+
+            if x > 0 {
+                self.inc(x - 1, y - 1, to_flash);
+            }
+
+            if x < self.field[0].len() - 1 {
+                self.inc(x + 1, y - 1, to_flash);
+            }
+        }
+
+        // increment below if in bounds, with diagonals if they're in bounds
+        if y < self.field.len() - 1 {
+            self.inc(x, y + 1, to_flash);
+
+            if x > 0 {
+                self.inc(x - 1, y + 1, to_flash);
+            }
+
+            if x < self.field[0].len() - 1 {
+                self.inc(x + 1, y + 1, to_flash);
+            }
+        }
+
+        // increment left if in bounds
+        if x > 0 {
+            self.inc(x - 1, y, to_flash);
+        }
+
+        // increment right if in bounds
+        if x < self.field[0].len() - 1 {
+            self.inc(x + 1, y, to_flash);
+        }
+    }
+
+    /// increments a field, and puts it in the deque if it got incremented to 10
+    fn inc(&mut self, x: usize, y: usize, to_flash: &mut VecDeque<(usize, usize)>) {
+        self.field[y][x] += 1;
+        if self.field[y][x] == 10 {
+            to_flash.push_back((x, y));
+        }
+    }
+
+    /// Returns the number of octopodes in the group
+    pub fn len(&self) -> usize {
+        self.field[0].len() * self.field.len()
+    }
+
+    /// Returns true if there are no octopodes in the group
+    pub fn is_empty(&self) -> bool {
+        self.field.is_empty() || self.field[0].is_empty()
+    }
+}
@@ -0,0 +1,300 @@
+//! Advent of Code problem 12
+//!
+//! Written by Trevor Leibert
+//! This solution can be sped up pretty drastically by pre-allocating
+//! enough storage for all the solution paths, but that feels
+//! a bit like cheating.
+
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::str::FromStr;
+
+pub struct Problem12;
+
+impl Solution for Problem12 {
+    const DAY: usize = 12;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_12.txt"),
+        params: &[],
+        answers: [Some("10"), Some("36")],
+    });
+
+    type Input = Graph<'static>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a link like `start-A`", |line| {
+            line.split_once('-').is_some_and(|(from, to)| {
+                [from, to]
+                    .iter()
+                    .all(|cave| !cave.is_empty() && cave.chars().all(|c| c.is_ascii_alphabetic()))
+            })
+        })?;
+        let caves: Vec<&str> = input
+            .lines()
+            .flat_map(|line| line.trim().split('-'))
+            .collect();
+        match ["start", "end"].iter().find(|cave| !caves.contains(cave)) {
+            Some(cave) => Err(format!("there's no `{}` cave", cave)),
+            None => Ok(()),
+        }
+    }
+
+    fn part_1(&self, caves: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        Ok(find_all_paths_1(caves, ctx)?.len().to_string())
+    }
+
+    fn part_2(&self, caves: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        Ok(find_all_paths_2(caves, ctx)?.len().to_string())
+    }
+}
+
+/// An undirected graph of caves. The cave names can borrow from the text
+/// it was built from, or be owned, as a `Graph<'static>` from [`FromStr`].
+pub struct Graph<'a> {
+    node_idx_map: HashMap<Cow<'a, str>, usize>,
+    idx_node_map: HashMap<usize, Cow<'a, str>>,
+    adj_matrix: Vec<Vec<u8>>,
+    len: usize,
+}
+
+type CaveNetwork<'a> = Graph<'a>;
+
+impl<'a> Debug for Graph<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let top: String = "         ".to_string()
+            + &(0..self.len)
+                .map(|i| format!(" {:5}", self.idx_node_map[&i]))
+                .collect::<String>();
+
+        let rows: String = (0..self.len)
+            .map(|i| {
+                format!("{:5}", self.idx_node_map[&i])
+                    + &self.adj_matrix[i]
+                        .iter()
+                        .map(|num| format!(" {:5}", num))
+                        .collect::<String>()
+                    + "\n"
+            })
+            .collect();
+        writeln!(f, "{}", top)?;
+        write!(f, "{}", rows)
+    }
+}
+
+impl FromStr for Graph<'static> {
+    type Err = ParseError;
+
+    /// parses the links between caves into a graph that owns its cave names
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        Graph::try_new(data).map(Graph::into_owned)
+    }
+}
+
+impl<'a> Graph<'a> {
+    /// panics if a line isn't a link like `start-A`, see [`Graph::try_new`]
+    pub fn new(data: &'a str) -> Self {
+        Self::try_new(data).unwrap()
+    }
+
+    /// builds a graph whose cave names borrow from `data`, one `a-b` link per line
+    pub fn try_new(data: &'a str) -> Result<Self, ParseError> {
+        let links = data
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.trim()
+                    .split_once('-')
+                    .filter(|(start, end)| !start.is_empty() && !end.is_empty())
+                    .ok_or(ParseError::BadLine {
+                        line: idx + 1,
+                        expected: "a link like `start-A`",
+                    })
+            })
+            .collect::<Result<Vec<(&str, &str)>, _>>()?;
+
+        // number the nodes in the order they first appear, so the
+        // layout (and the debug table) is the same on every run
+        let mut node_idx_map: HashMap<Cow<str>, usize> = HashMap::new();
+        for (start, end) in &links {
+            for part in [start, end] {
+                let next_idx = node_idx_map.len();
+                node_idx_map.entry(Cow::Borrowed(part)).or_insert(next_idx);
+            }
+        }
+        let len = node_idx_map.len();
+
+        let mut adj_matrix = vec![vec![0; len]; len];
+        for (start, end) in links {
+            let start = node_idx_map[start];
+            let end = node_idx_map[end];
+
+            adj_matrix[start][end] = 1;
+            // undirected graph so set other side the same
+            adj_matrix[end][start] = 1;
+        }
+
+        let idx_node_map = node_idx_map.iter().map(|(k, v)| (*v, k.clone())).collect();
+
+        Ok(Graph {
+            node_idx_map,
+            idx_node_map,
+            adj_matrix,
+            len,
+        })
+    }
+
+    /// copies the cave names so the graph no longer borrows the text it came from
+    pub fn into_owned(self) -> Graph<'static> {
+        let own = |name: Cow<str>| Cow::Owned(name.into_owned());
+        Graph {
+            node_idx_map: self
+                .node_idx_map
+                .into_iter()
+                .map(|(k, v)| (own(k), v))
+                .collect(),
+            idx_node_map: self
+                .idx_node_map
+                .into_iter()
+                .map(|(k, v)| (k, own(v)))
+                .collect(),
+            adj_matrix: self.adj_matrix,
+            len: self.len,
+        }
+    }
+
+    pub fn get_neighbors(&self, node: &str) -> HashSet<&str> {
+        let node = self.node_idx_map[node];
+        self.adj_matrix[node]
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, val)| {
+                if *val == 1 {
+                    Some(self.idx_node_map[&idx].as_ref())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// finds every path through the cave starting at "start" and ending at "end"
+/// only visiting small caves at most once
+fn find_all_paths_1<'a>(
+    caves: &'a CaveNetwork,
+    ctx: &Context,
+) -> Result<HashSet<Vec<&'a str>>, Error> {
+    let mut record = HashSet::new();
+
+    visit_1(caves, "start", Vec::new(), &mut record, ctx)?;
+
+    Ok(record)
+}
+
+fn visit_1<'a>(
+    caves: &'a CaveNetwork,
+    node: &'a str,
+    mut history: Vec<&'a str>,
+    record: &mut HashSet<Vec<&'a str>>,
+    ctx: &Context,
+) -> Result<(), Error> {
+    ctx.check(|| format!("found {} paths so far", record.len()))?;
+
+    history.push(node);
+    if node == "end" {
+        record.insert(history);
+        return Ok(());
+    }
+
+    let options = caves.get_neighbors(node);
+    for option in options {
+        // if we're looking at a lowercase/small cave we've seen before, don't go back
+        if option.to_lowercase() == option && history.contains(&option) {
+            continue;
+        }
+
+        // otherwise, visit this cave
+        visit_1(caves, option, history.clone(), record, ctx)?;
+    }
+
+    Ok(())
+}
+
+/// finds every path through the cave starting at "start" and ending at "end"
+/// visiting one small cave at most twice, and all other small caves only once
+fn find_all_paths_2<'a>(
+    caves: &'a CaveNetwork,
+    ctx: &Context,
+) -> Result<HashSet<Vec<&'a str>>, Error> {
+    let mut record = HashSet::new();
+
+    visit_2(caves, "start", Vec::new(), &mut record, ctx)?;
+
+    Ok(record)
+}
+
+fn visit_2<'a>(
+    caves: &'a CaveNetwork,
+    node: &'a str,
+    mut history: Vec<&'a str>,
+    record: &mut HashSet<Vec<&'a str>>,
+    ctx: &Context,
+) -> Result<(), Error> {
+    ctx.check(|| format!("found {} paths so far", record.len()))?;
+
+    history.push(node);
+    if node == "end" {
+        record.insert(history);
+        ctx.progress(record.len() as u64, None, "paths");
+        return Ok(());
+    }
+
+    // check if we've visited small caves more than once
+    let mut smalls = HashSet::new();
+    let mut has_time = true;
+    for item in &history {
+        if item.to_lowercase() == *item {
+            // if we've been in the same small cave twice, we don't
+            // have time to do that again
+            if !smalls.insert(*item) {
+                has_time = false;
+            }
+        }
+    }
+
+    let options = caves.get_neighbors(node);
+    for option in options {
+        // if we're looking at the start, don't go there
+        if option == "start" {
+            continue;
+        }
+
+        // if we have not visited a small cave twice, we can go back to a small cave
+        // otherwise, if we have visited a small cave twice, we cannot backtrack
+        if option.to_lowercase() == option && !has_time && smalls.contains(option) {
+            continue;
+        }
+
+        // otherwise, visit this cave
+        visit_2(caves, option, history.clone(), record, ctx)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Advent of Code problem 13
+//!
+//! Written By Trevor Leibert
+
+use crate::checkpoint::Checkpoint;
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::fmt::Debug;
+use std::str::FromStr;
+
+pub struct Problem13;
+
+impl Solution for Problem13 {
+    const DAY: usize = 13;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_13.txt"),
+        params: &[],
+        answers: [
+            Some("17"),
+            Some("#####\n#...#\n#...#\n#...#\n#####\n.....\n....."),
+        ],
+    });
+
+    /// the dotted paper, and the fold instructions
+    type Input = (Paper, Vec<String>);
+
+    fn parse(&self, input: &str) -> Self::Input {
+        let mut split = input.split("\n\n");
+        let positions = split.next().unwrap();
+        let instructions = split.next().unwrap();
+
+        let instructions = instructions.lines().map(str::to_string).collect();
+        (Paper::new(positions), instructions)
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::not_empty(input)?;
+        let Some((dots, folds)) = input.split_once("\n\n") else {
+            return Err("there's no blank line between the dots and the folds".to_string());
+        };
+        shape::each_line(dots, "a dot like `6,10`", shape::is_point)?;
+        shape::each_line(folds, "a fold like `fold along y=7`", |line| {
+            line.strip_prefix("fold along ")
+                .and_then(|fold| fold.split_once('='))
+                .is_some_and(|(axis, at)| {
+                    (axis == "x" || axis == "y") && at.parse::<usize>().is_ok()
+                })
+        })
+    }
+
+    fn part_1(&self, (paper, instructions): &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let mut paper = paper.clone();
+        paper.fold(&instructions[0]);
+
+        Ok(paper.count_dots().to_string())
+    }
+
+    fn part_2(&self, (paper, instructions): &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let (mut paper, start) = match ctx.resume_up_to::<Paper>(instructions.len())? {
+            Some(snapshot) => (snapshot.state, snapshot.step),
+            None => (paper.clone(), 0),
+        };
+        for (idx, instruction) in instructions.iter().enumerate().skip(start) {
+            ctx.check(|| format!("made {} of {} folds", idx, instructions.len()))?;
+            paper.fold(instruction);
+            ctx.checkpoint(idx + 1, 0, &paper)?;
+        }
+
+        // the code is spelled out by the dots
+        Ok(format!("{:?}", paper))
+    }
+}
+
+#[derive(Clone)]
+pub struct Paper {
+    paper: Vec<Vec<bool>>,
+}
+
+impl FromStr for Paper {
+    type Err = ParseError;
+
+    /// parses the dots, one `x,y` per line
+    fn from_str(positions: &str) -> Result<Self, Self::Err> {
+        let dots = positions
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| {
+                line.trim()
+                    .split_once(',')
+                    .and_then(|(x, y)| Some((x.parse().ok()?, y.parse().ok()?)))
+                    .ok_or(ParseError::BadLine {
+                        line: idx + 1,
+                        expected: "a dot like `6,10`",
+                    })
+            })
+            .collect::<Result<Vec<(usize, usize)>, _>>()?;
+
+        let max_x = dots
+            .iter()
+            .map(|(x, _)| *x)
+            .max()
+            .ok_or(ParseError::Empty)?;
+        let max_y = dots
+            .iter()
+            .map(|(_, y)| *y)
+            .max()
+            .ok_or(ParseError::Empty)?;
+
+        let mut paper = vec![vec![false; max_x + 1]; max_y + 1];
+        // populate the vec
+        for (x, y) in dots {
+            paper[y][x] = true;
+        }
+        Ok(Self { paper })
+    }
+}
+
+impl Checkpoint for Paper {
+    const KIND: &'static str = "paper";
+
+    /// the folded paper as `#` and `.` rows, the same as its [`Debug`] output
+    fn save(&self) -> String {
+        format!("{:?}", self)
+    }
+
+    fn load(text: &str) -> Result<Self, ParseError> {
+        let mut paper: Vec<Vec<bool>> = Vec::new();
+        for (y, line) in text.lines().enumerate() {
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, c)| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    found => Err(ParseError::UnexpectedChar {
+                        line: y + 1,
+                        column: x + 1,
+                        found,
+                    }),
+                })
+                .collect::<Result<Vec<bool>, _>>()?;
+
+            if let Some(first) = paper.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::Ragged {
+                        line: y + 1,
+                        width: row.len(),
+                        expected: first.len(),
+                    });
+                }
+            }
+            paper.push(row);
+        }
+
+        match paper.first() {
+            Some(row) if !row.is_empty() => Ok(Self { paper }),
+            _ => Err(ParseError::Empty),
+        }
+    }
+}
+
+impl Paper {
+    /// panics if a line isn't an `x,y` dot, see the [`FromStr`] impl
+    pub fn new(positions: &str) -> Self {
+        positions.parse().unwrap()
+    }
+
+    pub fn fold(&mut self, instruction: &str) {
+        let coord = instruction
+            .split('=')
+            .nth(1)
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        if instruction.contains('x') {
+            self.fold_left(coord);
+        } else {
+            self.fold_up(coord);
+        }
+    }
+
+    fn fold_up(&mut self, y: usize) {
+        let y_dist = self.y_len() - y;
+        for y_idx in 1..y_dist {
+            for x_idx in 0..self.x_len() {
+                self.paper[y - y_idx][x_idx] |= self.paper[y + y_idx][x_idx];
+            }
+        }
+
+        // shorten the array
+        self.paper.truncate(y);
+    }
+
+    fn fold_left(&mut self, x: usize) {
+        let x_dist = self.x_len() - x;
+        for x_idx in 1..x_dist {
+            for y_idx in 0..self.y_len() {
+                self.paper[y_idx][x - x_idx] |= self.paper[y_idx][x + x_idx];
+            }
+        }
+
+        // shorten the array
+        for row in &mut self.paper {
+            row.truncate(x);
+        }
+    }
+
+    pub fn y_len(&self) -> usize {
+        self.paper.len()
+    }
+
+    pub fn x_len(&self) -> usize {
+        self.paper[0].len()
+    }
+
+    pub fn count_dots(&self) -> usize {
+        self.paper
+            .iter()
+            .map(|row| row.iter().filter(|val| **val).count())
+            .sum()
+    }
+}
+
+impl Debug for Paper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let lines: Vec<String> = self
+            .paper
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|val| if *val { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
@@ -0,0 +1,340 @@
+//! Advent of Code problem 14
+//!
+//! Written by Trevor Leibert
+
+use crate::checkpoint::Checkpoint;
+use crate::params::{ParamKind, ParamSpec};
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    str::{self, FromStr},
+};
+
+pub struct Problem14;
+
+impl Solution for Problem14 {
+    const DAY: usize = 14;
+
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec::new(
+            "part_1_steps",
+            ParamKind::Usize,
+            "10",
+            "number of expansions for part 1",
+        ),
+        ParamSpec::new(
+            "part_2_steps",
+            ParamKind::Usize,
+            "40",
+            "number of expansions for part 2",
+        ),
+    ];
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_14.txt"),
+        params: &[],
+        answers: [Some("1588"), Some("2188189693529")],
+    });
+
+    type Input = Polymer;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::not_empty(input)?;
+        let Some((template, rules)) = input.split_once("\n\n") else {
+            return Err("there's no blank line between the template and the rules".to_string());
+        };
+        if !template.trim().chars().all(|c| c.is_ascii_uppercase()) {
+            return Err("the template isn't a line of elements".to_string());
+        }
+        shape::each_line(rules, "a rule like `CH -> B`", |line| {
+            line.split_once(" -> ").is_some_and(|(pair, insert)| {
+                pair.len() == 2
+                    && insert.len() == 1
+                    && pair
+                        .chars()
+                        .chain(insert.chars())
+                        .all(|c| c.is_ascii_uppercase())
+            })
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let steps = ctx.params.usize("part_1_steps");
+        Ok(expand(input, steps, ctx)?.max_min_diff().to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let steps = ctx.params.usize("part_2_steps");
+        Ok(expand(input, steps, ctx)?.max_min_diff().to_string())
+    }
+}
+
+/// expands the polymer `steps` times, or what's left of them after a checkpoint
+fn expand(input: &Polymer, steps: usize, ctx: &Context) -> Result<Polymer, Error> {
+    let (mut polymer, start) = match ctx.resume_up_to::<Polymer>(steps)? {
+        Some(snapshot) => (snapshot.state, snapshot.step),
+        None => (input.clone(), 0),
+    };
+    for step in start..steps {
+        ctx.check(|| format!("expanded {} of {} times", step, steps))?;
+        polymer.expand_once();
+        ctx.checkpoint(step + 1, 0, &polymer)?;
+    }
+
+    Ok(polymer)
+}
+
+#[derive(Debug, Clone)]
+pub struct Polymer {
+    chars: HashMap<char, usize>,
+    pairs: HashMap<String, usize>,
+    rules: HashMap<String, char>,
+}
+
+struct Diff {
+    new_pairs: [String; 2],
+    remove_pair: String,
+    letter: char,
+    quantity: usize,
+}
+
+impl FromStr for Polymer {
+    type Err = ParseError;
+
+    /// parses the puzzle input, the template then a blank line then the rules
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (state, rules) = s.split_once("\n\n").ok_or(ParseError::BadLine {
+            line: 2,
+            expected: "the blank line after the template",
+        })?;
+
+        Self::build(state.trim(), rules, state.lines().count() + 2)
+    }
+}
+
+impl Checkpoint for Polymer {
+    const KIND: &'static str = "polymer";
+
+    /// the element counts, pair counts and rules, a line each:
+    ///
+    /// ```text
+    /// chars B=1 C=2 H=1 N=2
+    /// pairs CB=1 NC=1 NN=1
+    /// rules CH=B HH=N
+    /// ```
+    fn save(&self) -> String {
+        fn line<K: ToString, V: ToString>(name: &str, map: &HashMap<K, V>) -> String {
+            let mut entries: Vec<String> = map
+                .iter()
+                .map(|(key, value)| format!("{}={}", key.to_string(), value.to_string()))
+                .collect();
+            entries.sort();
+            format!("{} {}", name, entries.join(" "))
+        }
+
+        [
+            line("chars", &self.chars),
+            line("pairs", &self.pairs),
+            line("rules", &self.rules),
+        ]
+        .join("\n")
+    }
+
+    fn load(text: &str) -> Result<Self, ParseError> {
+        fn line<'a>(
+            text: &'a str,
+            line: usize,
+            name: &str,
+            expected: &'static str,
+        ) -> Result<Vec<(&'a str, &'a str)>, ParseError> {
+            let bad = ParseError::BadLine { line, expected };
+            let entries = text
+                .lines()
+                .nth(line - 1)
+                .and_then(|entries| entries.strip_prefix(name))
+                .ok_or(bad.clone())?;
+            entries
+                .split_whitespace()
+                .map(|entry| entry.split_once('=').ok_or(bad.clone()))
+                .collect()
+        }
+
+        let expected = "`chars` followed by `element=count`s";
+        let chars = line(text, 1, "chars", expected)?
+            .into_iter()
+            .map(|(c, count)| {
+                let mut chars = c.chars();
+                match (chars.next(), chars.next(), count.parse()) {
+                    (Some(c), None, Ok(count)) => Ok((c, count)),
+                    _ => Err(ParseError::BadLine { line: 1, expected }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = "`pairs` followed by `pair=count`s";
+        let pairs = line(text, 2, "pairs", expected)?
+            .into_iter()
+            .map(|(pair, count)| match count.parse() {
+                Ok(count) if pair.len() == 2 => Ok((pair.to_string(), count)),
+                _ => Err(ParseError::BadLine { line: 2, expected }),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = "`rules` followed by `pair=element`s";
+        let rules = line(text, 3, "rules", expected)?
+            .into_iter()
+            .map(|(pair, insert)| {
+                let mut chars = insert.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if pair.len() == 2 => Ok((pair.to_string(), c)),
+                    _ => Err(ParseError::BadLine { line: 3, expected }),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            chars,
+            pairs,
+            rules,
+        })
+    }
+}
+
+impl Polymer {
+    /// panics if the template or rules are malformed, see the [`FromStr`] impl
+    pub fn new(state: &str, rules: &str) -> Self {
+        Self::build(state, rules, 1).unwrap()
+    }
+
+    /// `first_rule_line` is only used to number errors in the rules
+    fn build(state: &str, rules: &str, first_rule_line: usize) -> Result<Self, ParseError> {
+        if state.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        if let Some((column, found)) = state
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_uppercase())
+        {
+            return Err(ParseError::UnexpectedChar {
+                line: 1,
+                column: column + 1,
+                found,
+            });
+        }
+
+        let mut chars = HashMap::new();
+        for c in state.chars() {
+            chars.entry(c).and_modify(|count| *count += 1).or_insert(1);
+        }
+
+        let mut pairs = HashMap::new();
+
+        // get string slice windows assuming ascii string
+        for pair in state
+            .as_bytes()
+            .windows(2)
+            .map(|win| str::from_utf8(win).unwrap())
+        {
+            let pair = pair.to_string();
+            match pairs.entry(pair) {
+                Entry::Occupied(o) => *o.into_mut() += 1,
+                Entry::Vacant(v) => {
+                    v.insert(1);
+                }
+            };
+        }
+
+        let mut rule_map = HashMap::new();
+        for (idx, rule) in rules.lines().enumerate() {
+            let bad_rule = ParseError::BadLine {
+                line: first_rule_line + idx,
+                expected: "a rule like `CH -> B`",
+            };
+            let (pair, res) = rule.split_once("->").ok_or(bad_rule.clone())?;
+            let pair = pair.trim();
+            let res = res.trim();
+            let elements =
+                |s: &str, len| s.len() == len && s.chars().all(|c| c.is_ascii_uppercase());
+            if !elements(pair, 2) || !elements(res, 1) {
+                return Err(bad_rule);
+            }
+
+            rule_map.insert(pair.to_string(), res.chars().next().unwrap());
+        }
+
+        Ok(Self {
+            chars,
+            pairs,
+            rules: rule_map,
+        })
+    }
+
+    pub fn expand_once(&mut self) {
+        let mut diffs = Vec::new();
+        for (pair, new) in &self.rules {
+            if let Some(&count) = self.pairs.get(pair) {
+                if count == 0 {
+                    continue;
+                }
+
+                let mut first = pair[..1].to_string();
+                first.push(*new);
+                let mut second = new.to_string();
+                second.push_str(&pair[1..]);
+
+                let to_remove = pair.clone();
+
+                let diff = Diff {
+                    new_pairs: [first, second],
+                    remove_pair: to_remove,
+                    letter: *new,
+                    quantity: count,
+                };
+
+                diffs.push(diff);
+            }
+        }
+
+        for diff in diffs {
+            self.update(diff);
+        }
+    }
+
+    fn update(&mut self, diff: Diff) {
+        self.chars
+            .entry(diff.letter)
+            .and_modify(|count| *count += diff.quantity)
+            .or_insert(diff.quantity);
+
+        self.pairs
+            .entry(diff.remove_pair)
+            .and_modify(|count| *count -= diff.quantity);
+
+        for pair in diff.new_pairs {
+            self.pairs
+                .entry(pair)
+                .and_modify(|count| *count += diff.quantity)
+                .or_insert(diff.quantity);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.chars.values().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// difference between the most and least common elements
+    pub fn max_min_diff(&self) -> usize {
+        *self.chars.values().max().unwrap() - *self.chars.values().min().unwrap()
+    }
+}
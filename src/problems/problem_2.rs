@@ -0,0 +1,556 @@
+//! Advent of code problem 2
+//!
+//! Written by Trevor Leibert
+
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::fmt::Display;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+pub struct Problem2;
+
+impl Solution for Problem2 {
+    const DAY: usize = 2;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_2.txt"),
+        params: &[],
+        answers: [Some("150"), Some("900")],
+    });
+
+    type Input = Instructions;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a command like `forward 5`", |line| {
+            match line.split_once(' ') {
+                Some((command, amount)) => {
+                    ["forward", "up", "down"].contains(&command) && amount.parse::<i64>().is_ok()
+                }
+                None => false,
+            }
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let [position] = input.navigate([&Direct]);
+        Ok(position.product().to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let [position] = input.navigate([&Aim]);
+        Ok(position.product().to_string())
+    }
+}
+
+/// One line of the course
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i64),
+    Up(i64),
+    Down(i64),
+}
+
+impl Command {
+    /// parses line `line` of a course, which must be exactly a command word
+    /// and an amount separated by a space
+    pub(crate) fn parse_line(text: &str, line: usize) -> Result<Self, ParseError> {
+        let bad_line = ParseError::BadLine {
+            line,
+            expected: "a command like `forward 5`",
+        };
+        let (word, amount) = text.split_once(' ').ok_or(bad_line)?;
+        let amount_column = word.chars().count() + 2;
+
+        let amount = amount.parse().map_err(|_| ParseError::BadToken {
+            line,
+            column: amount_column,
+            found: amount.to_string(),
+            expected: "a whole number",
+        })?;
+        match word {
+            "forward" => Ok(Command::Forward(amount)),
+            "up" => Ok(Command::Up(amount)),
+            "down" => Ok(Command::Down(amount)),
+            _ => Err(ParseError::BadToken {
+                line,
+                column: 1,
+                found: word.to_string(),
+                expected: "`forward`, `up` or `down`",
+            }),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    /// parses a single command like `forward 5`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_line(s.trim_end_matches(['\r', '\n']), 1)
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Forward(amount) => write!(f, "forward {}", amount),
+            Command::Up(amount) => write!(f, "up {}", amount),
+            Command::Down(amount) => write!(f, "down {}", amount),
+        }
+    }
+}
+
+/// A whole course, checked up front so it can be applied without surprises
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instructions {
+    commands: Vec<Command>,
+}
+
+impl FromStr for Instructions {
+    type Err = ParseError;
+
+    /// parses one command per line, skipping blank lines
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let commands = s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| Command::parse_line(line.trim_end_matches('\r'), idx + 1))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { commands })
+    }
+}
+
+impl Instructions {
+    pub fn new(commands: Vec<Command>) -> Self {
+        Self { commands }
+    }
+
+    pub fn commands(&self) -> &[Command] {
+        &self.commands
+    }
+
+    /// follows every command in order with one model
+    pub fn apply(&self, model: &dyn Navigation, position: &mut Position) {
+        for command in &self.commands {
+            position.apply(model, *command);
+        }
+    }
+
+    /// follows the commands from the start with one model, keeping every position
+    pub fn trajectory(&self, model: &dyn Navigation) -> Trajectory {
+        Trajectory::record(model, Position::default(), self.commands.iter().copied())
+    }
+
+    /// undoes every command from the last back, giving where the sub must
+    /// have started to end up at `end`
+    pub fn retrace(&self, model: &dyn Reversible, end: Position) -> Position {
+        retrace(model, end, self.commands.iter().copied())
+    }
+
+    /// finds every way of changing one command so that the course takes the
+    /// sub from `start` to `observed` rather than where it should end up.
+    /// Empty if no single command explains it.
+    pub fn find_corruption(
+        &self,
+        model: &dyn Reversible,
+        start: Position,
+        observed: Position,
+    ) -> Vec<Corruption> {
+        // where the sub has to be after each command to still reach `observed`
+        let mut after = vec![observed; self.commands.len()];
+        for idx in (1..self.commands.len()).rev() {
+            after[idx - 1] = after[idx];
+            model.undo(&mut after[idx - 1], self.commands[idx]);
+        }
+
+        let mut before = start;
+        let mut corruptions = Vec::new();
+        for (index, (&found, after)) in self.commands.iter().zip(&after).enumerate() {
+            let mut unchanged = before;
+            unchanged.apply(model, found);
+            // a fix that moves the sub just like the command did explains
+            // nothing, like `down 0` for `forward 0` under the aim model
+            if unchanged != *after {
+                if let Some(fix) = model.between(before, *after) {
+                    if self.replay(model, start, index, fix) == observed {
+                        corruptions.push(Corruption { index, found, fix });
+                    }
+                }
+            }
+            before = unchanged;
+        }
+        corruptions
+    }
+
+    /// follows the commands from `start` with the one at `index` swapped for `fix`
+    fn replay(
+        &self,
+        model: &dyn Navigation,
+        start: Position,
+        index: usize,
+        fix: Command,
+    ) -> Position {
+        let mut position = start;
+        for (idx, &command) in self.commands.iter().enumerate() {
+            position.apply(model, if idx == index { fix } else { command });
+        }
+        position
+    }
+
+    /// follows the commands once from the start, with every model side by
+    /// side, see [`navigate_models`]
+    pub fn navigate<const N: usize>(&self, models: [&dyn Navigation; N]) -> [Position; N] {
+        navigate_models(self.commands.iter().copied(), models)
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+}
+
+/// How commands move the sub. The puzzle has two, [`Direct`] and [`Aim`],
+/// but anything can be plugged in to drive a [`Position`].
+pub trait Navigation {
+    fn apply(&self, position: &mut Position, command: Command);
+}
+
+/// A [`Navigation`] model that can be run backwards
+pub trait Reversible: Navigation {
+    /// moves back to where the sub was before `command`
+    fn undo(&self, position: &mut Position, command: Command);
+
+    /// the command that moves the sub from `from` to `to`, if there is one
+    fn between(&self, from: Position, to: Position) -> Option<Command>;
+}
+
+/// A command that can be swapped for another to explain where the sub ended
+/// up, see [`Instructions::find_corruption`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Corruption {
+    /// the index of the command in the course, from 0
+    pub index: usize,
+    pub found: Command,
+    /// what the command would have had to be
+    pub fix: Command,
+}
+
+/// undoes the commands from the last back, giving where the sub must have
+/// started to end up at `end`
+pub fn retrace(
+    model: &dyn Reversible,
+    end: Position,
+    commands: impl DoubleEndedIterator<Item = Command>,
+) -> Position {
+    let mut position = end;
+    for command in commands.rev() {
+        model.undo(&mut position, command);
+    }
+    position
+}
+
+/// `Down` for a dive and `Up` for a climb
+fn vertical(amount: i64) -> Command {
+    if amount < 0 {
+        Command::Up(-amount)
+    } else {
+        Command::Down(amount)
+    }
+}
+
+/// Part 1's model: up and down change the depth straight away
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Direct;
+
+impl Navigation for Direct {
+    fn apply(&self, position: &mut Position, command: Command) {
+        match command {
+            Command::Up(quantity) => position.depth -= quantity,
+            Command::Down(quantity) => position.depth += quantity,
+            Command::Forward(quantity) => position.horizontal += quantity,
+        }
+    }
+}
+
+impl Reversible for Direct {
+    fn undo(&self, position: &mut Position, command: Command) {
+        match command {
+            Command::Up(quantity) => position.depth += quantity,
+            Command::Down(quantity) => position.depth -= quantity,
+            Command::Forward(quantity) => position.horizontal -= quantity,
+        }
+    }
+
+    fn between(&self, from: Position, to: Position) -> Option<Command> {
+        let across = to.horizontal - from.horizontal;
+        let down = to.depth - from.depth;
+        if from.aim != to.aim {
+            None
+        } else if down == 0 {
+            Some(Command::Forward(across))
+        } else if across == 0 {
+            Some(vertical(down))
+        } else {
+            None
+        }
+    }
+}
+
+/// Part 2's model: up and down change the aim, and moving forward dives by
+/// the aim times the distance
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aim;
+
+impl Navigation for Aim {
+    fn apply(&self, position: &mut Position, command: Command) {
+        match command {
+            Command::Up(quantity) => position.aim -= quantity,
+            Command::Down(quantity) => position.aim += quantity,
+            Command::Forward(quantity) => {
+                position.horizontal += quantity;
+                position.depth += position.aim * quantity
+            }
+        };
+    }
+}
+
+impl Reversible for Aim {
+    fn undo(&self, position: &mut Position, command: Command) {
+        match command {
+            Command::Up(quantity) => position.aim += quantity,
+            Command::Down(quantity) => position.aim -= quantity,
+            Command::Forward(quantity) => {
+                position.horizontal -= quantity;
+                position.depth -= position.aim * quantity
+            }
+        };
+    }
+
+    fn between(&self, from: Position, to: Position) -> Option<Command> {
+        let across = to.horizontal - from.horizontal;
+        let down = to.depth - from.depth;
+        let turn = to.aim - from.aim;
+        if across == 0 && down == 0 {
+            Some(vertical(turn))
+        } else if turn == 0 && down == from.aim * across {
+            Some(Command::Forward(across))
+        } else {
+            None
+        }
+    }
+}
+
+/// follows the commands once, with each model moving its own sub from the
+/// start, so every model's answer comes from the same pass
+pub fn navigate_models<const N: usize>(
+    commands: impl IntoIterator<Item = Command>,
+    models: [&dyn Navigation; N],
+) -> [Position; N] {
+    let mut positions = [Position::default(); N];
+    for command in commands {
+        for (model, position) in models.iter().zip(&mut positions) {
+            position.apply(*model, command);
+        }
+    }
+    positions
+}
+
+/// Where the sub is. Models other than [`Aim`] are free to ignore `aim`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+impl Position {
+    pub fn new(horizontal: i64, depth: i64, aim: i64) -> Self {
+        Self {
+            horizontal,
+            depth,
+            aim,
+        }
+    }
+
+    /// panics if the command is malformed, see the [`FromStr`] impl of [`Command`]
+    pub fn update(&mut self, model: &dyn Navigation, command: &str) {
+        self.apply(model, command.parse().unwrap());
+    }
+
+    pub fn apply(&mut self, model: &dyn Navigation, command: Command) {
+        model.apply(self, command);
+    }
+
+    pub fn product(&self) -> i64 {
+        self.horizontal * self.depth
+    }
+}
+
+/// Every position a sub passed through, and the command that took it there
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    start: Position,
+    steps: Vec<(Command, Position)>,
+}
+
+/// The highlights of a [`Trajectory`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrajectorySummary {
+    pub max_depth: i64,
+    /// the first step the sub reached its deepest, 0 for the start
+    pub max_depth_step: usize,
+    /// how far the sub travelled, as the crow flies between each step
+    pub total_distance: f64,
+}
+
+impl Trajectory {
+    pub fn new(start: Position) -> Self {
+        Self {
+            start,
+            steps: Vec::new(),
+        }
+    }
+
+    /// records the course from `start` under one model
+    pub fn record(
+        model: &dyn Navigation,
+        start: Position,
+        commands: impl IntoIterator<Item = Command>,
+    ) -> Self {
+        let mut trajectory = Self::new(start);
+        for command in commands {
+            trajectory.follow(model, command);
+        }
+        trajectory
+    }
+
+    /// moves on from the latest position, recording where the sub ends up
+    pub fn follow(&mut self, model: &dyn Navigation, command: Command) {
+        let mut position = self.position();
+        position.apply(model, command);
+        self.steps.push((command, position));
+    }
+
+    /// the latest position
+    pub fn position(&self) -> Position {
+        self.steps
+            .last()
+            .map_or(self.start, |(_, position)| *position)
+    }
+
+    /// every position from the start on, so there's one more than there are steps
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        std::iter::once(self.start).chain(self.steps.iter().map(|(_, position)| *position))
+    }
+
+    /// the number of commands followed
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// writes one row per position, with the start as step 0:
+    ///
+    /// ```text
+    /// step,command,horizontal,depth,aim
+    /// 0,,0,0,0
+    /// 1,forward 5,5,0,0
+    /// ```
+    pub fn write_csv(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer, "step,command,horizontal,depth,aim")?;
+        let commands =
+            std::iter::once(None).chain(self.steps.iter().map(|(command, _)| Some(command)));
+        for (step, (command, position)) in commands.zip(self.positions()).enumerate() {
+            writeln!(
+                writer,
+                "{},{},{},{},{}",
+                step,
+                command.map(Command::to_string).unwrap_or_default(),
+                position.horizontal,
+                position.depth,
+                position.aim
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = Vec::new();
+        self.write_csv(&mut csv).unwrap();
+        String::from_utf8(csv).unwrap()
+    }
+
+    pub fn summary(&self) -> TrajectorySummary {
+        let (max_depth_step, max_depth) = self
+            .positions()
+            .map(|position| position.depth)
+            .enumerate()
+            .fold((0, self.start.depth), |(best_step, best), (step, depth)| {
+                if depth > best {
+                    (step, depth)
+                } else {
+                    (best_step, best)
+                }
+            });
+
+        let total_distance = self
+            .positions()
+            .zip(self.positions().skip(1))
+            .map(|(from, to)| {
+                let across = (to.horizontal - from.horizontal) as f64;
+                let down = (to.depth - from.depth) as f64;
+                across.hypot(down)
+            })
+            .sum();
+
+        TrajectorySummary {
+            max_depth,
+            max_depth_step,
+            total_distance,
+        }
+    }
+}
+
+/// follows every command from the start with the aim model, reading one
+/// line at a time, see [`navigate_with`]
+pub fn navigate(reader: impl BufRead) -> io::Result<Position> {
+    let [position] = navigate_with(reader, [&Aim])?;
+    Ok(position)
+}
+
+/// follows every command from the start with each model side by side,
+/// reading one line at a time. A malformed command fails with
+/// [`io::ErrorKind::InvalidData`].
+pub fn navigate_with<const N: usize>(
+    reader: impl BufRead,
+    models: [&dyn Navigation; N],
+) -> io::Result<[Position; N]> {
+    let mut positions = [Position::default(); N];
+
+    for (idx, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let command = Command::parse_line(line.trim_end_matches('\r'), idx + 1)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        for (model, position) in models.iter().zip(&mut positions) {
+            position.apply(*model, command);
+        }
+    }
+
+    Ok(positions)
+}
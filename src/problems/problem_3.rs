@@ -0,0 +1,416 @@
+//! Advent of code problem 3
+//!
+//! Written by Trevor Leibert
+
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::fmt::Display;
+use std::io::{self, BufRead, ErrorKind};
+use std::str::FromStr;
+
+pub struct Problem3;
+
+impl Solution for Problem3 {
+    const DAY: usize = 3;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_3.txt"),
+        params: &[],
+        answers: [Some("198"), Some("230")],
+    });
+
+    type Input = DiagnosticReport;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.parse().unwrap()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a binary number", |line| {
+            !line.is_empty() && line.bytes().all(|b| b == b'0' || b == b'1')
+        })?;
+        shape::same_width(input).map(|_| ())
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(input.power_consumption().to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(input.trie().life_support_rating().unwrap().to_string())
+    }
+}
+
+/// How many one bits appear in each column of a diagnostic report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitCounts {
+    /// number of ones in each column, most significant bit first
+    pub ones: Vec<usize>,
+    /// number of lines counted
+    pub lines: usize,
+}
+
+impl BitCounts {
+    /// counts the bits in each column, reading one line at a time. A line
+    /// that isn't a binary number as wide as the first, of at most 64 bits,
+    /// fails with [`ErrorKind::InvalidData`].
+    pub fn from_reader(reader: impl BufRead) -> io::Result<Self> {
+        let mut ones: Vec<usize> = Vec::new();
+        let mut lines = 0;
+
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if lines == 0 {
+                if line.len() > usize::BITS as usize {
+                    let err = ParseError::BadLine {
+                        line: idx + 1,
+                        expected: "a binary number of at most 64 bits",
+                    };
+                    return Err(io::Error::new(ErrorKind::InvalidData, err));
+                }
+                ones = vec![0; line.len()];
+            } else if line.len() != ones.len() {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "line {} has {} bits, expected {}",
+                        idx + 1,
+                        line.len(),
+                        ones.len()
+                    ),
+                ));
+            }
+
+            for (column, c) in line.chars().enumerate() {
+                ones[column] += match c {
+                    '0' => 0,
+                    '1' => 1,
+                    found => {
+                        let err = ParseError::UnexpectedChar {
+                            line: idx + 1,
+                            column: column + 1,
+                            found,
+                        };
+                        return Err(io::Error::new(ErrorKind::InvalidData, err));
+                    }
+                };
+            }
+            lines += 1;
+        }
+
+        Ok(Self { ones, lines })
+    }
+
+    /// number of zeros in a column
+    pub fn zeros(&self, column: usize) -> usize {
+        self.lines - self.ones[column]
+    }
+}
+
+impl BitCounts {
+    /// whether ones are at least as common as zeros in a column, so a tie
+    /// counts as one like it does for the oxygen generator rating
+    pub fn ones_common(&self, column: usize) -> bool {
+        self.ones[column] >= self.zeros(column)
+    }
+
+    /// the most common bit of each column
+    pub fn gamma(&self) -> usize {
+        (0..self.ones.len()).fold(0, |gamma, column| {
+            (gamma << 1) | self.ones_common(column) as usize
+        })
+    }
+
+    /// the least common bit of each column
+    pub fn epsilon(&self) -> usize {
+        let mask = match usize::BITS.checked_sub(self.ones.len() as u32) {
+            Some(shift) => usize::MAX.checked_shr(shift).unwrap_or(0),
+            // wider than a usize, so gamma only kept the last columns
+            None => usize::MAX,
+        };
+        !self.gamma() & mask
+    }
+
+    pub fn power_consumption(&self) -> usize {
+        self.gamma() * self.epsilon()
+    }
+}
+
+/// A whole diagnostic report, with its bits counted up front
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticReport {
+    numbers: Vec<usize>,
+    counts: BitCounts,
+}
+
+impl FromStr for DiagnosticReport {
+    type Err = ParseError;
+
+    /// parses one binary number per line, all the same width, skipping blank lines
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = Vec::new();
+        let mut ones: Vec<usize> = Vec::new();
+
+        for (idx, line) in s.lines().enumerate() {
+            let line_number = idx + 1;
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+
+            if numbers.is_empty() {
+                if line.len() > usize::BITS as usize {
+                    return Err(ParseError::BadLine {
+                        line: line_number,
+                        expected: "a binary number of at most 64 bits",
+                    });
+                }
+                ones = vec![0; line.len()];
+            } else if line.len() != ones.len() {
+                return Err(ParseError::Ragged {
+                    line: line_number,
+                    width: line.len(),
+                    expected: ones.len(),
+                });
+            }
+
+            let mut number = 0;
+            for (column, c) in line.chars().enumerate() {
+                let bit = match c {
+                    '0' => 0,
+                    '1' => 1,
+                    found => {
+                        return Err(ParseError::UnexpectedChar {
+                            line: line_number,
+                            column: column + 1,
+                            found,
+                        })
+                    }
+                };
+                ones[column] += bit;
+                number = (number << 1) | bit;
+            }
+            numbers.push(number);
+        }
+
+        if numbers.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let counts = BitCounts {
+            ones,
+            lines: numbers.len(),
+        };
+        Ok(Self { numbers, counts })
+    }
+}
+
+impl DiagnosticReport {
+    pub fn numbers(&self) -> &[usize] {
+        &self.numbers
+    }
+
+    pub fn counts(&self) -> &BitCounts {
+        &self.counts
+    }
+
+    /// the number of bits in each line
+    pub fn width(&self) -> usize {
+        self.counts.ones.len()
+    }
+
+    pub fn gamma(&self) -> usize {
+        self.counts.gamma()
+    }
+
+    pub fn epsilon(&self) -> usize {
+        self.counts.epsilon()
+    }
+
+    pub fn power_consumption(&self) -> usize {
+        self.counts.power_consumption()
+    }
+
+    /// keeps the numbers with the most common bit in each column in turn,
+    /// keeping ones on a tie
+    pub fn oxygen_rating(&self) -> usize {
+        self.filter_rating(true)
+    }
+
+    /// keeps the numbers with the least common bit in each column in turn,
+    /// keeping zeros on a tie
+    pub fn co2_rating(&self) -> usize {
+        self.filter_rating(false)
+    }
+
+    pub fn life_support_rating(&self) -> usize {
+        self.oxygen_rating() * self.co2_rating()
+    }
+
+    /// puts every number in a [`BitTrie`], for finding the ratings without
+    /// filtering
+    pub fn trie(&self) -> BitTrie {
+        let mut trie = BitTrie::new(self.width());
+        for number in &self.numbers {
+            trie.insert(*number);
+        }
+        trie
+    }
+
+    fn filter_rating(&self, most_common: bool) -> usize {
+        let mut candidates = self.numbers.clone();
+        let width = self.width();
+
+        let mut idx: usize = 0;
+        while candidates.len() > 1 && idx < width {
+            let mask = 1 << (width - idx - 1);
+            let one_count = candidates.iter().filter(|num| *num & mask != 0).count();
+            let zero_count = candidates.len() - one_count;
+
+            // if every candidate has the same bit, none of them can be
+            // ruled out by it
+            if one_count != 0 && zero_count != 0 {
+                let keep_ones = (one_count >= zero_count) == most_common;
+                candidates.retain(|num| (num & mask != 0) == keep_ones);
+            }
+
+            idx += 1;
+        }
+
+        // anything left after the last column is the same number
+        candidates[0]
+    }
+}
+
+/// A binary trie of a report's numbers, most significant bit first, where each
+/// node counts the numbers below it. Each rating is then a single walk from
+/// the root, picking a side by the counts rather than filtering every number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitTrie {
+    width: usize,
+    /// the root is node 0, so a child of 0 means there isn't one
+    nodes: Vec<TrieNode>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct TrieNode {
+    /// the zero and one children
+    children: [u32; 2],
+    count: usize,
+}
+
+impl BitTrie {
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            nodes: vec![TrieNode::default()],
+        }
+    }
+
+    /// adds a number, which must fit in the trie's width
+    pub fn insert(&mut self, number: usize) {
+        let mut node = 0;
+        self.nodes[0].count += 1;
+        for shift in (0..self.width).rev() {
+            let bit = (number >> shift) & 1;
+            let mut child = self.nodes[node].children[bit] as usize;
+            if child == 0 {
+                child = self.nodes.len();
+                self.nodes[node].children[bit] = child as u32;
+                self.nodes.push(TrieNode::default());
+            }
+            self.nodes[child].count += 1;
+            node = child;
+        }
+    }
+
+    /// how many numbers are in the trie
+    pub fn len(&self) -> usize {
+        self.nodes[0].count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// follows the most common bit, ones on a tie, or `None` if the trie is empty
+    pub fn oxygen_rating(&self) -> Option<usize> {
+        self.walk(|zeros, ones| ones >= zeros)
+    }
+
+    /// follows the least common bit, zeros on a tie, or `None` if the trie is empty
+    pub fn co2_rating(&self) -> Option<usize> {
+        self.walk(|zeros, ones| ones < zeros)
+    }
+
+    pub fn life_support_rating(&self) -> Option<usize> {
+        Some(self.oxygen_rating()? * self.co2_rating()?)
+    }
+
+    /// walks to a leaf, taking the one side if `take_one` says so given the
+    /// counts below each side, or whichever side there is if there's only one
+    fn walk(&self, take_one: impl Fn(usize, usize) -> bool) -> Option<usize> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut node = &self.nodes[0];
+        let mut number = 0;
+        for _ in 0..self.width {
+            let [zeros, ones] = node.children.map(|child| match child {
+                0 => 0,
+                child => self.nodes[child as usize].count,
+            });
+            let bit = if zeros == 0 || ones == 0 {
+                (ones != 0) as usize
+            } else {
+                take_one(zeros, ones) as usize
+            };
+            number = (number << 1) | bit;
+            node = &self.nodes[node.children[bit] as usize];
+        }
+        Some(number)
+    }
+}
+
+impl Display for DiagnosticReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Diagnostic report of {} lines", self.counts.lines)?;
+        writeln!(f, "column   ones  zeros  ones %  most common")?;
+        for column in 0..self.width() {
+            let ones = self.counts.ones[column];
+            writeln!(
+                f,
+                "{:>6} {:>6} {:>6} {:>6.1}  {}",
+                column + 1,
+                ones,
+                self.counts.zeros(column),
+                ones as f64 * 100.0 / self.counts.lines as f64,
+                self.counts.ones_common(column) as u8
+            )?;
+        }
+
+        writeln!(
+            f,
+            "Gamma {:0width$b} ({}), epsilon {:0width$b} ({}), power consumption {}",
+            self.gamma(),
+            self.gamma(),
+            self.epsilon(),
+            self.epsilon(),
+            self.power_consumption(),
+            width = self.width()
+        )?;
+        writeln!(
+            f,
+            "Oxygen generator {}, CO2 scrubber {}, life support {}",
+            self.oxygen_rating(),
+            self.co2_rating(),
+            self.life_support_rating()
+        )
+    }
+}
@@ -0,0 +1,225 @@
+//! Advent of Code problem 4
+//!
+//! Written by Trevor Leibert
+
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Problem4;
+
+impl Solution for Problem4 {
+    const DAY: usize = 4;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_4.txt"),
+        params: &[],
+        answers: [Some("4512"), Some("1924")],
+    });
+
+    /// the called numbers, and the boards in play
+    type Input = (Vec<usize>, Vec<Board>);
+
+    fn parse(&self, input: &str) -> Self::Input {
+        let mut groups = input.split("\n\n");
+        let moves: Vec<usize> = groups
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|s| s.trim().parse().unwrap())
+            .collect();
+
+        let mut boards = Vec::new();
+
+        for (idx, part) in groups.enumerate() {
+            boards.push(Board::new(idx, part))
+        }
+
+        (moves, boards)
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::not_empty(input)?;
+        let mut groups = input.trim_end().split("\n\n");
+        if !groups.next().is_some_and(shape::is_number_list) {
+            return Err("the first line isn't a list of called numbers".to_string());
+        }
+
+        for (idx, board) in groups.enumerate() {
+            let rows: Vec<&str> = board.lines().collect();
+            let square = rows.len() == 5
+                && rows.iter().all(|row| {
+                    let cells: Vec<&str> = row.split_whitespace().collect();
+                    cells.len() == 5 && cells.iter().all(|cell| cell.parse::<usize>().is_ok())
+                });
+            if !square {
+                return Err(format!("board {} isn't 5 rows of 5 numbers", idx + 1));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn part_1(&self, (moves, boards): &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let (_, score) = play_game_first_winner(boards.clone(), moves).unwrap();
+        Ok(score.to_string())
+    }
+
+    fn part_2(&self, (moves, boards): &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let (_, score) = play_game_last_winner(boards.clone(), moves).unwrap();
+        Ok(score.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Board {
+    id: usize,
+    board: [[usize; 5]; 5],
+    board_transpose: [[usize; 5]; 5],
+    space_map: HashMap<usize, (usize, usize)>, // map of numbers to board spaces
+    won: bool,
+}
+
+impl Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut grid = [[0; 5]; 5];
+        for (num, (i, j)) in &self.space_map {
+            grid[*i][*j] = *num;
+        }
+        let mut s = String::new();
+
+        for (i, line) in grid.iter().enumerate() {
+            for (j, num) in line.iter().enumerate() {
+                if self.board[i][j] == 0 {
+                    s.push_str(&num.to_string());
+                } else {
+                    s.push('x');
+                }
+
+                s.push(' ');
+            }
+
+            s.push('\n');
+        }
+
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Board {
+    type Err = ParseError;
+
+    /// parses a 5x5 layout of numbers, giving the board an id of 0
+    fn from_str(layout: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = layout.trim_matches('\n').lines().collect();
+        if rows.iter().all(|row| row.trim().is_empty()) {
+            return Err(ParseError::Empty);
+        }
+
+        let mut space_map = HashMap::new();
+        for (i, line) in rows.iter().enumerate() {
+            let cells: Vec<&str> = line.split_whitespace().collect();
+            if rows.len() != 5 || cells.len() != 5 {
+                return Err(ParseError::WrongSize {
+                    expected: (5, 5),
+                    found: (cells.len(), rows.len()),
+                });
+            }
+
+            for (j, cell) in cells.into_iter().enumerate() {
+                let num = cell.parse().map_err(|_| ParseError::BadLine {
+                    line: i + 1,
+                    expected: "a row of numbers",
+                })?;
+                space_map.insert(num, (i, j));
+            }
+        }
+
+        Ok(Self {
+            id: 0,
+            board: [[0; 5]; 5],
+            board_transpose: [[0; 5]; 5],
+            space_map,
+            won: false,
+        })
+    }
+}
+
+impl Board {
+    /// panics if the layout isn't a 5x5 grid of numbers, see the [`FromStr`] impl
+    pub fn new(id: usize, layout: &str) -> Self {
+        let board: Self = layout.parse().unwrap();
+        Self { id, ..board }
+    }
+
+    /// tries a move, returning if the move made this a winning board
+    pub fn play(&mut self, num: usize) -> bool {
+        if self.won {
+            return true;
+        }
+        match self.space_map.get(&num) {
+            Some((i, j)) => {
+                self.board[*i][*j] = 1;
+                self.board_transpose[*j][*i] = 1;
+                let row_win = self.board.iter().any(|row| row.iter().sum::<usize>() == 5);
+                let col_win = self
+                    .board_transpose
+                    .iter()
+                    .any(|col| col.iter().sum::<usize>() == 5);
+                self.won = row_win || col_win;
+                self.won
+            }
+            None => false,
+        }
+    }
+
+    pub fn score(&self, winning_call: usize) -> usize {
+        let sum_unmarked: usize = self
+            .space_map
+            .iter()
+            .filter(|(_, (i, j))| self.board[*i][*j] == 0)
+            .map(|(num, _)| *num)
+            .sum();
+
+        sum_unmarked * winning_call
+    }
+}
+
+/// plays the game until a board wins, returning which board won, and with what score
+/// If no board won, returns None
+fn play_game_first_winner(mut boards: Vec<Board>, moves: &[usize]) -> Option<(usize, usize)> {
+    for play in moves {
+        for (idx, board) in boards.iter_mut().enumerate() {
+            if board.play(*play) {
+                return Some((idx, board.score(*play)));
+            }
+        }
+    }
+
+    None
+}
+
+fn play_game_last_winner(mut boards: Vec<Board>, moves: &[usize]) -> Option<(usize, usize)> {
+    for play in moves {
+        // only one board left, play it till it wins
+        if boards.len() == 1 {
+            if !boards[0].play(*play) {
+                continue;
+            };
+            return Some((boards[0].id, boards[0].score(*play)));
+        } else {
+            let mut new_boards = Vec::new();
+            for mut board in boards {
+                let won = board.play(*play);
+                if !won {
+                    new_boards.push(board);
+                }
+            }
+            boards = new_boards;
+        }
+    }
+    None
+}
@@ -0,0 +1,148 @@
+//! Advent of Code problem 5
+//!
+//! Written by Trevor Leibert
+
+use crate::params::{ParamKind, ParamSpec};
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::cmp::Ordering;
+
+pub struct Problem5;
+
+impl Solution for Problem5 {
+    const DAY: usize = 5;
+
+    const PARAMS: &'static [ParamSpec] = &[ParamSpec::new(
+        "grid_size",
+        ParamKind::Usize,
+        "1000",
+        "width and height of the vent field",
+    )
+    .with_serve_max(4096)];
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_5.txt"),
+        params: &["grid_size=10"],
+        answers: [Some("5"), Some("12")],
+    });
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a line like `0,9 -> 5,9`", |line| {
+            line.split_once("->")
+                .is_some_and(|(start, end)| shape::is_point(start) && shape::is_point(end))
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let mut field = Field::new(ctx.params.usize("grid_size"));
+        for (idx, line) in input.lines().enumerate() {
+            ctx.check(|| format!("drew {} lines", idx))?;
+            field.add_line_no_diagonal(line);
+        }
+
+        Ok(field.num_intersects().to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let mut field = Field::new(ctx.params.usize("grid_size"));
+        for (idx, line) in input.lines().enumerate() {
+            ctx.check(|| format!("drew {} lines", idx))?;
+            field.add_line_diagonal(line);
+        }
+
+        Ok(field.num_intersects().to_string())
+    }
+}
+
+pub struct Field {
+    grid: Vec<Vec<u8>>,
+}
+
+impl Field {
+    pub fn new(dim: usize) -> Self {
+        Self {
+            grid: vec![vec![0; dim]; dim],
+        }
+    }
+
+    fn parse_str(line: &str) -> ((usize, usize), (usize, usize)) {
+        let mut split = line.split("->");
+        let part_1 = split.next().unwrap();
+        let part_2 = split.next().unwrap();
+
+        let mut split = part_1.trim().split(',');
+        let start_x: usize = split.next().unwrap().parse().unwrap();
+        let start_y: usize = split.next().unwrap().parse().unwrap();
+
+        let mut split = part_2.trim().split(',');
+        let end_x: usize = split.next().unwrap().parse().unwrap();
+        let end_y: usize = split.next().unwrap().parse().unwrap();
+        ((start_x, start_y), (end_x, end_y))
+    }
+
+    pub fn add_line_no_diagonal(&mut self, line: &str) {
+        let ((mut x_pos, mut y_pos), (end_x, end_y)) = Self::parse_str(line);
+
+        if y_pos == end_y {
+            // drawing horizontal line
+            while x_pos != end_x {
+                self.grid[y_pos][x_pos] += 1;
+                match x_pos.cmp(&end_x) {
+                    Ordering::Greater => x_pos -= 1,
+                    Ordering::Less => x_pos += 1,
+                    Ordering::Equal => (),
+                }
+            }
+
+            self.grid[y_pos][x_pos] += 1;
+        } else if x_pos == end_x {
+            // drawing vertical line
+            while y_pos != end_y {
+                self.grid[y_pos][x_pos] += 1;
+                match y_pos.cmp(&end_y) {
+                    Ordering::Greater => y_pos -= 1,
+                    Ordering::Less => y_pos += 1,
+                    Ordering::Equal => (),
+                }
+            }
+
+            self.grid[y_pos][x_pos] += 1;
+        }
+    }
+
+    pub fn add_line_diagonal(&mut self, line: &str) {
+        // drawing the dreaded diagonal line
+        let ((mut x_pos, mut y_pos), (end_x, end_y)) = Self::parse_str(line);
+
+        while x_pos != end_x || y_pos != end_y {
+            self.grid[y_pos][x_pos] += 1;
+            match x_pos.cmp(&end_x) {
+                Ordering::Greater => x_pos -= 1,
+                Ordering::Less => x_pos += 1,
+                Ordering::Equal => (),
+            }
+
+            match y_pos.cmp(&end_y) {
+                Ordering::Greater => y_pos -= 1,
+                Ordering::Less => y_pos += 1,
+                Ordering::Equal => (),
+            }
+        }
+
+        // need to draw the last one, since the line is inclusive
+        self.grid[y_pos][x_pos] += 1;
+    }
+
+    pub fn num_intersects(&self) -> usize {
+        self.grid
+            .iter()
+            .map(|row| row.iter().filter(|element| **element > 1).count())
+            .sum()
+    }
+}
@@ -0,0 +1,216 @@
+//! Advent of Code problem 6
+//!
+//! Written by Trevor Leibert
+
+use crate::checkpoint::Checkpoint;
+use crate::cycle::{self, Simulation};
+use crate::params::{ParamKind, ParamSpec};
+use crate::parse::ParseError;
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+
+pub struct Problem6;
+
+impl Solution for Problem6 {
+    const DAY: usize = 6;
+
+    const PARAMS: &'static [ParamSpec] = &[
+        ParamSpec::new(
+            "part_1_days",
+            ParamKind::Usize,
+            "80",
+            "number of days to simulate for part 1",
+        ),
+        ParamSpec::new(
+            "part_2_days",
+            ParamKind::Usize,
+            "256",
+            "number of days to simulate for part 2",
+        ),
+        ParamSpec::new(
+            "modulus",
+            ParamKind::Usize,
+            "0",
+            "count the fish modulo this, skipping ahead once the counts repeat (0 counts exactly)",
+        ),
+    ];
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_6.txt"),
+        params: &[],
+        answers: [Some("5934"), Some("26984457539")],
+    });
+
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input
+            .trim()
+            .split(',')
+            .map(|num| num.parse().unwrap())
+            .collect()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a list of fish timers from 0 to 8", |line| {
+            line.split(',')
+                .all(|timer| timer.parse::<u8>().is_ok_and(|timer| timer <= 8))
+        })?;
+        match input.trim().lines().count() {
+            1 => Ok(()),
+            lines => Err(format!("expected one line of fish, found {}", lines)),
+        }
+    }
+
+    fn part_1(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let n = ctx.params.usize("part_1_days");
+        count_fish(input, n, ctx)
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let n = ctx.params.usize("part_2_days");
+        count_fish(input, n, ctx)
+    }
+}
+
+/// counts the fish after `num_days`, exactly or modulo the `modulus` param
+fn count_fish(initial_state: &[usize], num_days: usize, ctx: &Context) -> Result<String, Error> {
+    match ctx.params.usize("modulus") {
+        0 => Ok(fish_calculus(initial_state, num_days, ctx)?.to_string()),
+        modulus => {
+            if ctx.checkpoints.every.is_some() || ctx.checkpoints.resume.is_some() {
+                return Err(Error::Checkpoint(
+                    "day 6 only checkpoints when counting exactly, with `modulus=0`".to_string(),
+                ));
+            }
+            let school = School::new(initial_state).modulo(modulus as u64);
+            let (school, _) = cycle::after(&school, num_days as u64, ctx)?;
+            Ok(school.len().to_string())
+        }
+    }
+}
+
+fn fish_calculus(initial_state: &[usize], num_days: usize, ctx: &Context) -> Result<usize, Error> {
+    let (mut school, start) = match ctx.resume_up_to::<School>(num_days)? {
+        Some(snapshot) => (snapshot.state, snapshot.step),
+        None => (School::new(initial_state), 0),
+    };
+
+    for day in start..num_days {
+        // a day is only a few instructions, so don't look at the clock every time
+        if day % 1024 == 0 {
+            ctx.check(|| {
+                format!(
+                    "simulated {} of {} days, {} fish so far",
+                    day,
+                    num_days,
+                    school.len()
+                )
+            })?;
+        }
+
+        school.day();
+        ctx.checkpoint(day + 1, 0, &school)?;
+    }
+
+    Ok(school.len())
+}
+
+/// How many fish there are with each timer value, from 0 to 8
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct School {
+    counts: [usize; 9],
+}
+
+impl School {
+    pub fn new(timers: &[usize]) -> Self {
+        let mut counts = [0; 9];
+        for timer in timers {
+            counts[*timer] += 1;
+        }
+        Self { counts }
+    }
+
+    /// every timer ticks down, and each fish at 0 resets to 6 and spawns a fish at 8
+    pub fn day(&mut self) {
+        self.counts.rotate_left(1);
+        self.counts[6] += self.counts[8];
+    }
+
+    /// the number of fish in the school
+    pub fn len(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// the school with its counts taken modulo `modulus`, see [`SchoolModulo`]
+    pub fn modulo(&self, modulus: u64) -> SchoolModulo {
+        SchoolModulo {
+            counts: self.counts.map(|count| count as u64 % modulus),
+            modulus,
+        }
+    }
+}
+
+/// A [`School`] with its counts taken modulo some number, for asking about
+/// far off days.
+///
+/// The exact counts grow exponentially, so they never repeat and there's no
+/// cycle to find. Modulo `m` there are only `m^9` states, so they have to
+/// repeat, and because a day can be undone they repeat all the way back to
+/// day 0. Keep `m` small, as the cycle can be almost as long as there are states.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchoolModulo {
+    counts: [u64; 9],
+    modulus: u64,
+}
+
+impl SchoolModulo {
+    /// the number of fish in the school, modulo the modulus
+    pub fn len(&self) -> u64 {
+        self.counts.iter().sum::<u64>() % self.modulus
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// the fish are counted from the state afterwards, so days don't add anything up
+impl Simulation for SchoolModulo {
+    fn advance(&mut self) -> u64 {
+        self.counts.rotate_left(1);
+        self.counts[6] = (self.counts[6] + self.counts[8]) % self.modulus;
+        0
+    }
+}
+
+impl Checkpoint for School {
+    const KIND: &'static str = "fish buckets";
+
+    /// the nine counts, fish with timer 0 first
+    fn save(&self) -> String {
+        self.counts
+            .iter()
+            .map(|count| count.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn load(text: &str) -> Result<Self, ParseError> {
+        let bad = ParseError::BadLine {
+            line: 1,
+            expected: "nine comma separated fish counts",
+        };
+        let counts: Vec<usize> = text
+            .trim()
+            .split(',')
+            .map(|count| count.parse().map_err(|_| bad.clone()))
+            .collect::<Result<_, _>>()?;
+        let counts = counts.try_into().map_err(|_| bad)?;
+        Ok(Self { counts })
+    }
+}
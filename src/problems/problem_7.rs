@@ -0,0 +1,86 @@
+//! Advent of Code problem 7
+//!
+//! Written by Trevor Leibert
+
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+
+pub struct Problem7;
+
+impl Solution for Problem7 {
+    const DAY: usize = 7;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_7.txt"),
+        params: &[],
+        answers: [Some("37"), Some("168")],
+    });
+
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input
+            .trim()
+            .split(',')
+            .map(|num| num.parse().unwrap())
+            .collect()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "a list of crab positions", shape::is_number_list)?;
+        match input.trim().lines().count() {
+            1 => Ok(()),
+            lines => Err(format!("expected one line of crabs, found {}", lines)),
+        }
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(calculate_with_median(&mut input.clone()).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        Ok(calculate_optimum_position(input, ctx)?.to_string())
+    }
+}
+
+/// Part 1 solution: the median minimizes the distance to all the other elements
+fn calculate_with_median(array: &mut [i32]) -> i32 {
+    array.sort();
+
+    let median = if array.len().is_multiple_of(2) {
+        let idx = array.len() / 2;
+        (array[idx - 1] + array[idx]) / 2
+    } else {
+        array[array.len() / 2]
+    };
+
+    array.iter().map(|num| (num - median).abs()).sum()
+}
+
+/// Part 2 solution
+fn calculate_optimum_position(array: &[i32], ctx: &Context) -> Result<i32, Error> {
+    let min = *array.iter().min().unwrap();
+    let max = *array.iter().max().unwrap();
+    let positions = (max - min + 1) as u64;
+
+    let best = (min..=max).try_fold(i32::MAX, |best, start| -> Result<_, Error> {
+        ctx.check(|| {
+            format!(
+                "checked {} of {} positions, least fuel so far {}",
+                start - min,
+                positions,
+                best
+            )
+        })?;
+        ctx.progress((start - min) as u64, Some(positions), "positions");
+
+        let fuel = array
+            .iter()
+            .map(|end| (1..=((*end - start).abs())).sum::<i32>())
+            .sum();
+        Ok(best.min(fuel))
+    })?;
+
+    ctx.progress(positions, Some(positions), "positions");
+    Ok(best)
+}
@@ -0,0 +1,179 @@
+//! Advent of Code problem 8
+//!
+//! Written by Trevor Leibert
+
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::collections::{BTreeSet, HashMap};
+
+pub struct Problem8;
+
+impl Solution for Problem8 {
+    const DAY: usize = 8;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_8.txt"),
+        params: &[],
+        answers: [Some("26"), Some("61229")],
+    });
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        shape::each_line(input, "ten patterns, `|` and four digits", |line| {
+            line.split_once('|').is_some_and(|(patterns, digits)| {
+                patterns.split_whitespace().count() == 10 && digits.split_whitespace().count() == 4
+            })
+        })
+    }
+
+    fn part_1(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(easy_digits(input).to_string())
+    }
+
+    fn part_2(&self, input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Ok(full_solution(input).to_string())
+    }
+}
+
+fn easy_digits(data: &str) -> usize {
+    data.lines()
+        .map(|line| {
+            line.split('|')
+                .nth(1)
+                .unwrap()
+                .split_whitespace()
+                .filter(|entry| {
+                    entry.len() == 2 || entry.len() == 4 || entry.len() == 3 || entry.len() == 7
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// populates the map of numbers to the signals in them
+fn populate_map(digit_data: &str) -> HashMap<usize, BTreeSet<char>> {
+    let mut digit_map = HashMap::<usize, BTreeSet<char>>::new();
+    let mut unclassified = HashMap::<usize, BTreeSet<BTreeSet<char>>>::new();
+
+    // populate map with "easy" digits first
+    for digit in digit_data.split_whitespace() {
+        match digit.len() {
+            2 => {
+                digit_map.insert(1, digit.chars().collect());
+            }
+            4 => {
+                digit_map.insert(4, digit.chars().collect());
+            }
+            3 => {
+                digit_map.insert(7, digit.chars().collect());
+            }
+            7 => {
+                digit_map.insert(8, digit.chars().collect());
+            }
+            len => {
+                unclassified
+                    .entry(len)
+                    .or_default()
+                    .insert(digit.chars().collect());
+            }
+        }
+    }
+
+    // 6 is missing ONE of 1's values
+    let six = unclassified[&6]
+        .iter()
+        .find(|six_candidate| !six_candidate.is_superset(&digit_map[&1]))
+        .unwrap()
+        .clone();
+    unclassified.get_mut(&6).unwrap().remove(&six);
+    digit_map.insert(6, six);
+
+    // bottom right segment is the intersection of 1 and 6
+    let lower_right = *digit_map[&1].intersection(&digit_map[&6]).next().unwrap();
+
+    // 3 is the only 5-segment number that has 1 contained in it
+    let three = unclassified[&5]
+        .iter()
+        .find(|three_candidate| three_candidate.is_superset(&digit_map[&1]))
+        .unwrap()
+        .clone();
+    unclassified.get_mut(&5).unwrap().remove(&three);
+    digit_map.insert(3, three);
+
+    // 5 is the only remaining 5-segment number with lower-right in it
+    let five = unclassified[&5]
+        .iter()
+        .find(|five_candidate| five_candidate.contains(&lower_right))
+        .unwrap()
+        .clone();
+    unclassified.get_mut(&5).unwrap().remove(&five);
+    digit_map.insert(5, five);
+
+    // 2 is the only remaining unclassified 5-segment number
+    let two = unclassified[&5]
+        .iter()
+        .next()
+        .unwrap()
+        .iter()
+        .cloned()
+        .collect();
+    unclassified.get_mut(&5).unwrap().remove(&two);
+    digit_map.insert(2, two);
+
+    // 9 is the only remaining 6-segment number with 3 as a subset
+    let nine = unclassified[&6]
+        .iter()
+        .find(|nine_candidate| nine_candidate.is_superset(&digit_map[&3]))
+        .unwrap()
+        .clone();
+    unclassified.get_mut(&6).unwrap().remove(&nine);
+    digit_map.insert(9, nine);
+
+    // 0 is the only remaining unclassified number, 6-segment or otherwise
+    let zero = unclassified[&6]
+        .iter()
+        .next()
+        .unwrap()
+        .iter()
+        .cloned()
+        .collect();
+    unclassified.get_mut(&6).unwrap().remove(&zero);
+    digit_map.insert(0, zero);
+
+    digit_map
+}
+
+/// finds the true output of each display and sums it
+fn full_solution(data: &str) -> usize {
+    data.lines()
+        .map(|line| {
+            let mut parts = line.split('|');
+            let digit_data = parts.next().unwrap();
+
+            // create map of digits to the segment representing them
+            let digit_map = populate_map(digit_data);
+
+            // flip map so we can map from chars to number
+            let char_map = digit_map
+                .into_iter()
+                .map(|(num, chars)| (chars, num))
+                .collect::<HashMap<BTreeSet<char>, usize>>();
+
+            let disp_data = parts.next().unwrap();
+            let mut place_val = 10000;
+            disp_data
+                .split_whitespace()
+                .map(|digit| {
+                    place_val /= 10;
+                    let converted = digit.chars().collect();
+                    char_map[&converted] * place_val
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
@@ -0,0 +1,174 @@
+//! Advent of Code problem 9
+//!
+//! Written by Trevor Leibert
+
+use crate::parse::{self, ParseError};
+use crate::shape;
+use crate::solution::{Context, Error, Example, Solution};
+use std::str::FromStr;
+
+pub struct Problem9;
+
+impl Solution for Problem9 {
+    const DAY: usize = 9;
+
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_9.txt"),
+        params: &[],
+        answers: [Some("15"), Some("1134")],
+    });
+
+    type Input = HeightMap;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        HeightMap::new(input)
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        match shape::digit_grid(input)? {
+            (100, 100) => Ok(()),
+            (width, height) => Err(format!(
+                "the height map is {}x{}, but puzzle inputs are always 100x100",
+                width, height
+            )),
+        }
+    }
+
+    fn part_1(&self, heightmap: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        let risk_sum: usize = heightmap
+            .find_low_points()
+            .iter()
+            .map(|(x, y)| (heightmap.get(*x, *y) + 1) as usize)
+            .sum();
+
+        Ok(risk_sum.to_string())
+    }
+
+    fn part_2(&self, heightmap: &Self::Input, ctx: &Context) -> Result<String, Error> {
+        let low_points = heightmap.find_low_points();
+        let mut basin_areas = Vec::with_capacity(low_points.len());
+        for (x, y) in &low_points {
+            ctx.check(|| {
+                format!(
+                    "measured {} of {} basins",
+                    basin_areas.len(),
+                    low_points.len()
+                )
+            })?;
+            basin_areas.push(heightmap.basin_area(*x, *y));
+        }
+        basin_areas.sort();
+        let three_biggest_sum: usize = basin_areas.iter().rev().take(3).product();
+
+        Ok(three_biggest_sum.to_string())
+    }
+}
+
+/// Struct implementing the height map and useful functions on it
+#[derive(Debug)]
+pub struct HeightMap {
+    data: Vec<Vec<u8>>,
+    xlen: usize,
+    ylen: usize,
+}
+
+impl FromStr for HeightMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data = parse::digit_grid(s)?;
+        let xlen = data[0].len();
+        let ylen = data.len();
+
+        Ok(Self { data, xlen, ylen })
+    }
+}
+
+impl HeightMap {
+    /// creates a heightmap from a rectangular grid of the chars 0-9,
+    /// panicking if it isn't one, see the [`FromStr`] impl
+    pub fn new(data: &str) -> Self {
+        data.parse().unwrap()
+    }
+
+    /// returns the indicies of all the local minima in the heightmap
+    pub fn find_low_points(&self) -> Vec<(usize, usize)> {
+        let mut ret = Vec::new();
+
+        for (y, row) in self.data.iter().enumerate() {
+            for (x, num) in row.iter().enumerate() {
+                let num = *num;
+                let mut above_greater = true;
+                let mut left_greater = true;
+                let mut right_greater = true;
+                let mut below_greater = true;
+
+                if y > 0 {
+                    above_greater = num < self.get(x, y - 1);
+                }
+
+                if y < self.ylen - 1 {
+                    below_greater = num < self.get(x, y + 1);
+                }
+
+                if x > 0 {
+                    left_greater = num < self.get(x - 1, y);
+                }
+
+                if x < self.xlen - 1 {
+                    right_greater = num < self.get(x + 1, y);
+                }
+
+                if above_greater && left_greater && below_greater && right_greater {
+                    ret.push((x, y));
+                }
+            }
+        }
+
+        ret
+    }
+
+    /// gets a copy of the data in the point
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.data[y][x]
+    }
+
+    /// finds the area of the basin containing this value
+    pub fn basin_area(&self, x: usize, y: usize) -> usize {
+        // let's keep a record of searched areas
+        let mut searched = vec![vec![false; self.xlen]; self.ylen];
+        self.search(&mut searched, x, y);
+
+        // the number of areas we've searched is the area of the basin
+        searched
+            .into_iter()
+            .map(|row| row.into_iter().filter(|val| *val).count())
+            .sum()
+    }
+
+    /// helper recursive function to search through a basin
+    fn search(&self, searched: &mut Vec<Vec<bool>>, x: usize, y: usize) {
+        searched[y][x] = true;
+        // we want to search in a direction if:
+        // - we aren't at that edge
+        // - we haven't looked there
+        // - if the value there isn't 9
+
+        // search above
+        if y > 0 && !searched[y - 1][x] && self.get(x, y - 1) < 9 {
+            self.search(searched, x, y - 1);
+        }
+        // search below
+        if y < self.ylen - 1 && !searched[y + 1][x] && self.get(x, y + 1) < 9 {
+            self.search(searched, x, y + 1);
+        }
+        // search to the left
+        if x > 0 && !searched[y][x - 1] && self.get(x - 1, y) < 9 {
+            self.search(searched, x - 1, y);
+        }
+        // search to the right
+        if x < self.xlen - 1 && !searched[y][x + 1] && self.get(x + 1, y) < 9 {
+            self.search(searched, x + 1, y);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Progress reports from long-running solvers
+//!
+//! Written by Trevor Leibert
+
+use std::fmt::Debug;
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::runner::format_duration;
+
+/// How far a part has got, as reported through
+/// [`Context::progress`](crate::solution::Context::progress)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub day: usize,
+    pub part: usize,
+    /// how much work has been done, in `unit`s
+    pub done: u64,
+    /// how much work there is altogether, if the solver knows
+    pub total: Option<u64>,
+    /// what's being counted, like `paths` or `steps`
+    pub unit: &'static str,
+}
+
+/// Something to tell about progress. Solvers report as often as every step,
+/// so it should be cheap and do its own throttling.
+#[derive(Clone)]
+pub struct Reporter(Arc<dyn Fn(&Progress) + Send + Sync>);
+
+impl Reporter {
+    pub fn new(report: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(report))
+    }
+
+    pub fn report(&self, progress: &Progress) {
+        (self.0)(progress)
+    }
+}
+
+impl Debug for Reporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Reporter")
+    }
+}
+
+/// A progress line on stderr, rewritten in place at most every `interval`
+pub struct ProgressLine {
+    interval: Duration,
+    state: Mutex<LineState>,
+}
+
+struct LineState {
+    /// the part being reported on, and when its first report came in
+    part: Option<(usize, usize, Instant)>,
+    printed: Option<Instant>,
+    /// how long the line on screen is, so it can be blanked out
+    width: usize,
+}
+
+impl ProgressLine {
+    pub fn new(interval: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            interval,
+            state: Mutex::new(LineState {
+                part: None,
+                printed: None,
+                width: 0,
+            }),
+        })
+    }
+
+    /// a reporter that prints to this line
+    pub fn reporter(self: &Arc<Self>) -> Reporter {
+        let line = Arc::clone(self);
+        Reporter::new(move |progress| line.report(progress))
+    }
+
+    pub fn report(&self, progress: &Progress) {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let started = match state.part {
+            Some((day, part, started)) if (day, part) == (progress.day, progress.part) => started,
+            _ => {
+                // rate and elapsed time start again for each part
+                state.part = Some((progress.day, progress.part, now));
+                state.printed = None;
+                now
+            }
+        };
+        if state
+            .printed
+            .is_some_and(|printed| now - printed < self.interval)
+        {
+            return;
+        }
+
+        let line = render(progress, now - started);
+        let padding = state.width.saturating_sub(line.chars().count());
+        eprint!("\r{}{:padding$}", line, "");
+        io::stderr().flush().unwrap();
+        state.width = line.chars().count();
+        state.printed = Some(now);
+    }
+
+    /// blanks out the line, so normal output can take its place
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.width > 0 {
+            eprint!("\r{:width$}\r", "", width = state.width);
+            io::stderr().flush().unwrap();
+        }
+        *state = LineState {
+            part: None,
+            printed: None,
+            width: 0,
+        };
+    }
+}
+
+/// like `day 12 part 2: 51234 paths, 25617 paths/s, 2.00 s`
+pub fn render(progress: &Progress, elapsed: Duration) -> String {
+    let done = match progress.total {
+        Some(total) if total > 0 => format!(
+            "{} of {} {} ({:.0}%)",
+            progress.done,
+            total,
+            progress.unit,
+            progress.done as f64 * 100.0 / total as f64
+        ),
+        _ => format!("{} {}", progress.done, progress.unit),
+    };
+
+    let secs = elapsed.as_secs_f64();
+    let rate = if secs > 0.0 {
+        format!("{:.0} {}/s", progress.done as f64 / secs, progress.unit)
+    } else {
+        format!("- {}/s", progress.unit)
+    };
+
+    format!(
+        "day {} part {}: {}, {}, {}",
+        progress.day,
+        progress.part,
+        done,
+        rate,
+        format_duration(elapsed)
+    )
+}
@@ -0,0 +1,115 @@
+//! Markdown report of every day's answers and timings
+//!
+//! Written by Trevor Leibert
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::cancel::CancelToken;
+use crate::params::Params;
+use crate::problems::DAYS;
+use crate::runner::{catch_panic, format_duration, input_path, load_input};
+use crate::solution::{Context, Error, Run};
+
+/// the commit the binary was built from, if `GIT_COMMIT` was set at build time
+pub fn git_commit() -> Option<&'static str> {
+    option_env!("GIT_COMMIT").filter(|commit| !commit.is_empty())
+}
+
+/// The outcome of running one day for the report
+pub struct DayReport {
+    pub day: usize,
+    /// the run, or why the input couldn't be read or the day panicked
+    pub result: Result<Run, Error>,
+}
+
+/// runs every day against its input with the default parameters,
+/// giving each day up to `timeout` if there is one
+pub fn run_all(timeout: Option<Duration>) -> Vec<DayReport> {
+    DAYS.iter()
+        .map(|solution| {
+            let day = solution.day();
+            // a malformed input makes the parse panic, which only fails its own day
+            let result = load_input(day).and_then(|input| {
+                let cancel = timeout.map_or_else(CancelToken::new, CancelToken::with_timeout);
+                let ctx = Context::new(Params::defaults(solution.params())).with_cancel(cancel);
+                catch_panic(|| Ok(solution.run(&input, &ctx)))
+            });
+
+            DayReport { day, result }
+        })
+        .collect()
+}
+
+/// renders the reports as a Markdown document, with a table per day
+pub fn render(reports: &[DayReport]) -> String {
+    let mut out = String::new();
+    writeln!(out, "# Advent of Code 2021 report\n").unwrap();
+    match git_commit() {
+        Some(commit) => writeln!(out, "Built from commit `{}`.", commit).unwrap(),
+        None => writeln!(out, "Built from an unknown commit.").unwrap(),
+    }
+
+    let mut total = Duration::ZERO;
+    for report in reports {
+        writeln!(out, "\n## Day {}\n", report.day).unwrap();
+
+        let run = match &report.result {
+            Ok(run) => run,
+            Err(err @ Error::Panicked(_)) => {
+                writeln!(out, "| Step | Answer | Time |").unwrap();
+                writeln!(out, "| --- | --- | ---: |").unwrap();
+                writeln!(out, "| Run | _{}_ | |", table_cell(&err.to_string())).unwrap();
+                continue;
+            }
+            Err(err) => {
+                writeln!(out, "Skipped: {}", err).unwrap();
+                continue;
+            }
+        };
+
+        writeln!(
+            out,
+            "Input `{}`, checksum `{}`\n",
+            input_path(report.day).display(),
+            run.checksum
+        )
+        .unwrap();
+        writeln!(out, "| Step | Answer | Time |").unwrap();
+        writeln!(out, "| --- | --- | ---: |").unwrap();
+        writeln!(out, "| Parse | | {} |", format_duration(run.parse_time)).unwrap();
+        for (idx, part) in run.parts.iter().enumerate() {
+            let answer = match &part.answer {
+                Ok(answer) => table_cell(answer),
+                Err(err) => format!("_{}_", err),
+            };
+            writeln!(
+                out,
+                "| Part {} | {} | {} |",
+                idx + 1,
+                answer,
+                format_duration(part.time)
+            )
+            .unwrap();
+        }
+
+        total += run.parse_time + run.parts.iter().map(|part| part.time).sum::<Duration>();
+    }
+
+    writeln!(out, "\nTotal time: {}", format_duration(total)).unwrap();
+    out
+}
+
+/// escapes an answer so it fits in a single table cell
+pub(crate) fn table_cell(answer: &str) -> String {
+    let escaped = answer.replace('|', "\\|");
+    if escaped.contains('\n') {
+        // multi-line answers (like day 13's letters) need to keep their shape
+        format!(
+            "<code>{}</code>",
+            escaped.lines().collect::<Vec<_>>().join("<br>")
+        )
+    } else {
+        escaped
+    }
+}
@@ -0,0 +1,439 @@
+//! Loading inputs, running days and printing their answers
+//!
+//! Written by Trevor Leibert
+
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::cancel::CancelToken;
+use crate::checkpoint::{Checkpoints, Saved};
+use crate::params::Params;
+use crate::problems;
+use crate::progress::ProgressLine;
+use crate::solution::{Context, Day, Error, Example, Run};
+
+/// how often `--progress` rewrites its line
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Options for running a day, taken from the command line
+#[derive(Debug, Default)]
+pub struct RunOptions {
+    /// the `--param` overrides, left for [`Params::from_args`] to parse
+    pub params: Vec<String>,
+    /// how long the whole run may take before it's cancelled
+    pub timeout: Option<Duration>,
+    /// record the answers as verified for this input
+    pub record: bool,
+    /// save a checkpoint of long simulations every this many steps
+    pub checkpoint_every: Option<usize>,
+    /// a checkpoint to pick the run up from
+    pub resume: Option<PathBuf>,
+    /// show how far the slower solvers have got on stderr
+    pub progress: bool,
+    /// solve the day's worked example instead of its input
+    pub example: bool,
+}
+
+impl RunOptions {
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Result<Self, Error> {
+        let mut options = Self::default();
+        let mut args = args.iter().map(AsRef::as_ref);
+
+        while let Some(arg) = args.next() {
+            if arg == "--timeout" {
+                let value = args
+                    .next()
+                    .ok_or_else(|| Error::Usage("`--timeout` needs a duration".to_string()))?;
+                options.timeout = Some(parse_duration(value)?);
+            } else if let Some(value) = arg.strip_prefix("--timeout=") {
+                options.timeout = Some(parse_duration(value)?);
+            } else if arg == "--record" {
+                options.record = true;
+            } else if arg == "--progress" {
+                options.progress = true;
+            } else if arg == "--example" {
+                options.example = true;
+            } else if arg == "--checkpoint-every" {
+                let value = args.next().ok_or_else(|| {
+                    Error::Usage("`--checkpoint-every` needs a number of steps".to_string())
+                })?;
+                options.checkpoint_every = Some(parse_steps(value)?);
+            } else if let Some(value) = arg.strip_prefix("--checkpoint-every=") {
+                options.checkpoint_every = Some(parse_steps(value)?);
+            } else if arg == "--resume" {
+                let value = args.next().ok_or_else(|| {
+                    Error::Usage("`--resume` needs a checkpoint file".to_string())
+                })?;
+                options.resume = Some(PathBuf::from(value));
+            } else if let Some(value) = arg.strip_prefix("--resume=") {
+                options.resume = Some(PathBuf::from(value));
+            } else {
+                options.params.push(arg.to_string());
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// the first option given that only makes sense for a single run, like `--record`
+    pub fn run_only_flag(&self) -> Option<&'static str> {
+        if self.record {
+            Some("--record")
+        } else if self.checkpoint_every.is_some() {
+            Some("--checkpoint-every")
+        } else if self.resume.is_some() {
+            Some("--resume")
+        } else if self.progress {
+            Some("--progress")
+        } else if self.example {
+            Some("--example")
+        } else {
+            None
+        }
+    }
+
+    /// how the run saves checkpoints, and the one it resumes from if
+    /// it's for `day`
+    pub fn checkpoints(&self, day: usize) -> Result<Checkpoints, Error> {
+        let resume = match &self.resume {
+            Some(path) => {
+                let saved = Saved::read(path)?;
+                if saved.day != day {
+                    return Err(Error::Checkpoint(format!(
+                        "{} is a checkpoint of day {}, not day {}",
+                        path.display(),
+                        saved.day,
+                        day
+                    )));
+                }
+                Some(saved)
+            }
+            None => None,
+        };
+
+        Ok(Checkpoints {
+            every: self.checkpoint_every,
+            dir: checkpoints_dir(),
+            resume,
+        })
+    }
+
+    /// a cancellation token that honours the timeout
+    pub fn cancel_token(&self) -> CancelToken {
+        self.timeout
+            .map_or_else(CancelToken::new, CancelToken::with_timeout)
+    }
+}
+
+/// parses a duration like `90`, `90s`, `1.5s`, `500ms` or `2m`; bare numbers are seconds
+pub fn parse_duration(value: &str) -> Result<Duration, Error> {
+    let (number, scale) = if let Some(ms) = value.strip_suffix("ms") {
+        (ms, 1e-3)
+    } else if let Some(s) = value.strip_suffix('s') {
+        (s, 1.0)
+    } else if let Some(m) = value.strip_suffix('m') {
+        (m, 60.0)
+    } else {
+        (value, 1.0)
+    };
+
+    number
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * scale).ok())
+        .ok_or_else(|| Error::Usage(format!("`{}` is not a duration", value)))
+}
+
+fn parse_steps(value: &str) -> Result<usize, Error> {
+    value
+        .parse()
+        .ok()
+        .filter(|steps| *steps > 0)
+        .ok_or_else(|| Error::Usage(format!("`{}` is not a number of steps", value)))
+}
+
+/// where a day's puzzle input lives
+pub fn input_path(day: usize) -> PathBuf {
+    PathBuf::from(format!("inputs/input_{}.txt", day))
+}
+
+/// where the verified answers are kept, see [`Answers`]
+pub fn answers_path() -> PathBuf {
+    PathBuf::from("inputs/answers.txt")
+}
+
+/// where checkpoints are saved, see [`Checkpoints`]
+pub fn checkpoints_dir() -> PathBuf {
+    PathBuf::from("checkpoints")
+}
+
+/// reads a day's puzzle input
+pub fn load_input(day: usize) -> Result<String, Error> {
+    let path = input_path(day);
+    fs::read_to_string(&path).map_err(|source| Error::Input { path, source })
+}
+
+/// looks up a day's solution
+pub fn find_day(day: usize) -> Result<&'static dyn Day, Error> {
+    problems::get(day).ok_or(Error::UnknownDay(day))
+}
+
+/// solves one part of a day, turning a panic from malformed input into
+/// an error so callers serving other people's inputs keep going
+pub fn solve_part(
+    solution: &dyn Day,
+    input: &str,
+    part: usize,
+    ctx: &Context,
+) -> Result<String, Error> {
+    catch_panic(|| solution.solve(input, part, ctx))
+}
+
+/// runs `solve`, turning a panic into [`Error::Panicked`]
+pub fn catch_panic<T>(solve: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string());
+        Err(Error::Panicked(reason))
+    })
+}
+
+/// A day's run against its input file, or its worked example
+#[derive(Debug)]
+pub struct DayRun {
+    pub run: Run,
+    /// how the answers compare with the verified ones, or the example's,
+    /// `None` if parameters were overridden so they can't be compared
+    pub verdicts: Option<[Verdict; 2]>,
+    /// true if the day was run against its example
+    pub example: bool,
+}
+
+/// runs a day against its input, with parameter overrides and runner
+/// options taken from `args`. Warns straight away if the input doesn't
+/// look like the day's, since the wrong input can make the parse panic.
+pub fn run_day<S: AsRef<str>>(day: usize, args: &[S]) -> Result<DayRun, Error> {
+    let solution = find_day(day)?;
+    let options = RunOptions::from_args(args)?;
+    let defaults = options.params.is_empty();
+    if options.record && !defaults {
+        return Err(Error::Usage(
+            "only answers with the default parameters can be recorded".to_string(),
+        ));
+    }
+
+    let example = match options.example {
+        true => Some(
+            solution
+                .example()
+                .ok_or_else(|| Error::Usage(format!("day {} has no example", day)))?,
+        ),
+        false => None,
+    };
+    if options.record && example.is_some() {
+        return Err(Error::Usage(
+            "only answers to the puzzle input can be recorded".to_string(),
+        ));
+    }
+
+    // the example's own overrides go first, so `--param` can still change them
+    let example_params = example
+        .iter()
+        .flat_map(|example| example.params)
+        .map(|pair| format!("--param={}", pair));
+    let params = Params::from_args(
+        solution.params(),
+        example_params.chain(options.params.iter().cloned()),
+    )?;
+
+    let checkpoints = options.checkpoints(day)?;
+    let input = match example {
+        Some(example) => example.input.to_string(),
+        None => load_input(day)?,
+    };
+    let path = answers_path();
+    let mut answers = match example {
+        Some(_) => Answers::default(),
+        None => Answers::load(&path)?,
+    };
+    if let (Err(problem), None) = (solution.check_shape(&input), example) {
+        eprintln!(
+            "warning: {} doesn't look like a day {} input, {}",
+            input_path(day).display(),
+            day,
+            problem
+        );
+    }
+
+    let mut ctx = Context::new(params)
+        .with_cancel(options.cancel_token())
+        .with_checkpoints(checkpoints);
+    let line = options
+        .progress
+        .then(|| ProgressLine::new(PROGRESS_INTERVAL));
+    if let Some(line) = &line {
+        ctx = ctx.with_progress(line.reporter());
+    }
+    let run = solution.run(&input, &ctx);
+    if let Some(line) = &line {
+        line.clear();
+    }
+
+    if options.record {
+        let recorded = answers.record(&run);
+        answers.save(&path).map_err(|source| Error::Input {
+            path: path.clone(),
+            source,
+        })?;
+        eprintln!(
+            "Recorded {} answers for day {} in {}",
+            recorded,
+            day,
+            path.display()
+        );
+    }
+
+    let verdicts = defaults.then(|| match example {
+        Some(example) => check_example(&example, &run),
+        None => answers.check(&run),
+    });
+    Ok(DayRun {
+        run,
+        verdicts,
+        example: example.is_some(),
+    })
+}
+
+/// compares each part of a run with the example's answer. Parts that
+/// aren't solved yet are left unchecked rather than called wrong.
+pub fn check_example(example: &Example, run: &Run) -> [Verdict; 2] {
+    [0, 1].map(|idx| match (example.answers[idx], &run.parts[idx].answer) {
+        (None, _) | (_, Err(Error::Unsolved)) => Verdict::Unchecked,
+        (Some(expected), Ok(answer)) if answer == expected => Verdict::Verified,
+        (Some(expected), _) => Verdict::Wrong {
+            expected: expected.to_string(),
+        },
+    })
+}
+
+/// true if any part's answer is wrong
+pub fn any_wrong(day_run: &DayRun) -> bool {
+    day_run
+        .verdicts
+        .iter()
+        .flatten()
+        .any(|verdict| matches!(verdict, Verdict::Wrong { .. }))
+}
+
+/// true if any part of the run was cancelled before it finished
+pub fn was_cancelled(run: &Run) -> bool {
+    run.parts
+        .iter()
+        .any(|part| matches!(part.answer, Err(Error::Cancelled { .. })))
+}
+
+/// prints the answers and timings of a run, and how they compare with
+/// the verified answers
+pub fn print_run(
+    DayRun {
+        run,
+        verdicts,
+        example,
+    }: &DayRun,
+) {
+    let unchecked = [Verdict::Unchecked, Verdict::Unchecked];
+    let verdicts = verdicts.as_ref().unwrap_or(&unchecked);
+    let changed = verdicts.iter().find_map(|verdict| match verdict {
+        Verdict::InputChanged { recorded } => Some(recorded),
+        _ => None,
+    });
+    if let Some(recorded) = changed {
+        eprintln!(
+            "!!! WARNING: {} has changed since its answers were verified\n\
+             !!! (checksum was {}, now {}), check it's the right input for day {}",
+            input_path(run.day).display(),
+            recorded,
+            run.checksum,
+            run.day
+        );
+    }
+
+    println!(
+        "Day {}{} (parsed in {})",
+        run.day,
+        if *example { " example" } else { "" },
+        format_duration(run.parse_time)
+    );
+    let (matched, expected) = match example {
+        true => ("matches the example", "the example's answer is"),
+        false => ("verified", "the verified answer is"),
+    };
+
+    for (idx, (part, verdict)) in run.parts.iter().zip(verdicts).enumerate() {
+        let time = match verdict {
+            Verdict::Verified => format!("{}, {}", format_duration(part.time), matched),
+            _ => format_duration(part.time),
+        };
+        match &part.answer {
+            Ok(answer) if answer.contains('\n') => {
+                println!("Part {} ({}):\n{}", idx + 1, time, answer)
+            }
+            Ok(answer) => println!("Part {}: {} ({})", idx + 1, answer, time),
+            Err(err) => println!("Part {}: {}", idx + 1, err),
+        }
+
+        if let Verdict::Wrong { expected: answer } = verdict {
+            let separator = if answer.contains('\n') { '\n' } else { ' ' };
+            println!("  WRONG, {}{}{}", expected, separator, answer);
+        }
+    }
+}
+
+/// formats a duration with a unit that suits its size
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2} s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2} ms", secs * 1e3)
+    } else {
+        format!("{:.2} µs", secs * 1e6)
+    }
+}
+
+/// entry point for the `problem_N` binaries
+pub fn main(day: usize) {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        let params = find_day(day)
+            .map(|solution| solution.params())
+            .unwrap_or(&[]);
+        println!("{}", Params::usage(params));
+        return;
+    }
+
+    match run_day(day, &args) {
+        Ok(day_run) => {
+            print_run(&day_run);
+            if was_cancelled(&day_run.run) || (day_run.example && any_wrong(&day_run)) {
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            if let Error::Params(_) = err {
+                eprintln!("\n{}", Params::usage(find_day(day).unwrap().params()));
+            }
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Scaffolding for starting a new day
+//!
+//! Written by Trevor Leibert
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+const MODULE_TEMPLATE: &str = r#"//! Advent of Code problem {day}
+//!
+//! Written by Trevor Leibert
+
+use crate::solution::{Context, Error, Example, Solution};
+
+pub struct Problem{day};
+
+impl Solution for Problem{day} {
+    const DAY: usize = {day};
+
+    // TODO: paste the example input into examples/day_{day}.txt and fill in its answers
+    const EXAMPLE: Option<Example> = Some(Example {
+        input: include_str!("examples/day_{day}.txt"),
+        params: &[],
+        answers: [None, None],
+    });
+
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Self::Input {
+        input.to_string()
+    }
+
+    // TODO: check the input is laid out like the puzzle input, see `crate::shape`
+    fn check_shape(&self, _input: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn part_1(&self, _input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Err(Error::Unsolved)
+    }
+
+    fn part_2(&self, _input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Err(Error::Unsolved)
+    }
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"//! Advent of Code problem {day}
+//!
+//! Written by Trevor Leibert
+
+fn main() {
+    advent_of_code_2021::runner::main({day});
+}
+"#;
+
+const TEST_TEMPLATE: &str = r#"//! Checks problem {day} against the example from the puzzle text
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::problems::problem_{day}::Problem{day};
+use advent_of_code_2021::solution::{Context, Solution};
+
+// TODO: paste the example input from the puzzle text into this file
+const EXAMPLE: &str = include_str!("../src/problems/examples/day_{day}.txt");
+
+fn context() -> Context {
+    Context::new(Params::defaults(Problem{day}::PARAMS))
+}
+
+#[test]
+#[ignore = "fill in the example input and answer"]
+fn part_1_example() {
+    let input = Problem{day}.parse(EXAMPLE);
+    assert_eq!(Problem{day}.part_1(&input, &context()).unwrap(), "TODO");
+}
+
+#[test]
+#[ignore = "fill in the example input and answer"]
+fn part_2_example() {
+    let input = Problem{day}.parse(EXAMPLE);
+    assert_eq!(Problem{day}.part_2(&input, &context()).unwrap(), "TODO");
+}
+"#;
+
+/// Files created for a new day, relative to the repository root
+struct DayFiles {
+    module: PathBuf,
+    example: PathBuf,
+    bin: PathBuf,
+    test: PathBuf,
+    input: PathBuf,
+}
+
+impl DayFiles {
+    fn new(root: &Path, day: usize) -> Self {
+        Self {
+            module: root.join(format!("src/problems/problem_{}.rs", day)),
+            example: root.join(format!("src/problems/examples/day_{}.txt", day)),
+            bin: root.join(format!("src/bin/problem_{}.rs", day)),
+            test: root.join(format!("tests/problem_{}.rs", day)),
+            input: root.join(format!("inputs/input_{}.txt", day)),
+        }
+    }
+
+    fn all(&self) -> [&PathBuf; 5] {
+        [
+            &self.module,
+            &self.example,
+            &self.bin,
+            &self.test,
+            &self.input,
+        ]
+    }
+}
+
+/// creates the module, empty example, binary, example test and empty input for a new day,
+/// and registers the module with the runner. Nothing is written if any of
+/// the files already exist. Returns the paths it created or changed.
+pub fn new_day(root: &Path, day: usize) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            ErrorKind::InvalidInput,
+            format!("day {} isn't between 1 and 25", day),
+        ));
+    }
+
+    let registry = root.join("src/problems/mod.rs");
+    let modules = fs::read_to_string(&registry).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!(
+                "couldn't read {} (is this the repository root?): {}",
+                registry.display(),
+                err
+            ),
+        )
+    })?;
+
+    let files = DayFiles::new(root, day);
+    if let Some(existing) = files.all().into_iter().find(|path| path.exists()) {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists, not overwriting it", existing.display()),
+        ));
+    }
+
+    // work out the registration before touching anything, so a registry
+    // we can't make sense of doesn't leave a half-created day behind
+    let modules = register(&modules, day)?;
+
+    let fill = |template: &str| template.replace("{day}", &day.to_string());
+    fs::write(&files.module, fill(MODULE_TEMPLATE))?;
+    fs::create_dir_all(root.join("src/problems/examples"))?;
+    fs::write(&files.example, "")?;
+    fs::write(&files.bin, fill(BIN_TEMPLATE))?;
+    fs::create_dir_all(root.join("tests"))?;
+    fs::write(&files.test, fill(TEST_TEMPLATE))?;
+    fs::create_dir_all(root.join("inputs"))?;
+    fs::write(&files.input, "")?;
+    fs::write(&registry, modules)?;
+
+    let mut created: Vec<PathBuf> = files.all().into_iter().cloned().collect();
+    created.push(registry);
+    Ok(created)
+}
+
+/// adds a day's `mod` declaration and `DAYS` entry to the registry source
+fn register(source: &str, day: usize) -> io::Result<String> {
+    let unrecognised = |what: &str| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("couldn't find {} in src/problems/mod.rs", what),
+        )
+    };
+
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    // `mod` declarations are kept in rustfmt's order, which sorts the names as strings
+    let name = format!("problem_{}", day);
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|idx| lines[*idx].starts_with("pub mod problem_"))
+        .collect();
+    let last_mod = *mods
+        .last()
+        .ok_or_else(|| unrecognised("the `mod` declarations"))?;
+    let mod_idx = mods
+        .iter()
+        .copied()
+        .find(|idx| mod_name(&lines[*idx]) > name.as_str())
+        .unwrap_or(last_mod + 1);
+    lines.insert(mod_idx, format!("pub mod {};", name));
+
+    // `DAYS` entries are kept in day order
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or_else(|| unrecognised("`DAYS`"))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| unrecognised("the end of `DAYS`"))?;
+    let entry_idx = (start + 1..end)
+        .find(|idx| entry_day(&lines[*idx]).is_some_and(|existing| existing > day))
+        .unwrap_or(end);
+    lines.insert(entry_idx, format!("    &problem_{}::Problem{},", day, day));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// the module name of a `pub mod problem_N;` line
+fn mod_name(line: &str) -> &str {
+    line.trim_start_matches("pub mod ").trim_end_matches(';')
+}
+
+/// the day of a `&problem_N::ProblemN,` registry line
+fn entry_day(line: &str) -> Option<usize> {
+    line.trim()
+        .strip_prefix("&problem_")?
+        .split("::")
+        .next()?
+        .parse()
+        .ok()
+}
@@ -0,0 +1,311 @@
+//! Submarine scripts: day 2 courses with loops, macros and comments
+//!
+//! Written by Trevor Leibert
+//!
+//! A script is the day 2 command language with a few extras, one per line:
+//!
+//! ```text
+//! # everything after a `#` is a comment
+//! macro zigzag {
+//!     down 1
+//!     forward 2
+//!     up 1
+//! }
+//!
+//! repeat 1000000 {
+//!     zigzag
+//!     forward 1
+//! }
+//! ```
+//!
+//! Macros are defined at the top level and can only use macros defined
+//! above them, so they can't recurse. Blocks are expanded lazily as the
+//! commands are read, so a long course doesn't take up any more memory.
+
+use std::str::FromStr;
+
+use crate::parse::ParseError;
+use crate::problems::problem_2::{navigate_models, Command, Navigation, Position};
+
+/// One line of a script, with any block it opens
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Command(Command),
+    Repeat {
+        times: u64,
+        body: Vec<Item>,
+        /// how many commands the whole loop runs
+        len: u128,
+    },
+    /// uses the macro with this index
+    Call {
+        index: usize,
+        len: u128,
+    },
+}
+
+impl Item {
+    fn len(&self) -> u128 {
+        match self {
+            Item::Command(_) => 1,
+            Item::Repeat { len, .. } | Item::Call { len, .. } => *len,
+        }
+    }
+}
+
+/// A parsed script, ready to be followed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    items: Vec<Item>,
+    macros: Vec<Macro>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Macro {
+    name: String,
+    body: Vec<Item>,
+    len: u128,
+}
+
+/// A block that's still open while parsing
+struct Open {
+    /// the line the block started on
+    line: usize,
+    kind: Block,
+    body: Vec<Item>,
+}
+
+enum Block {
+    Repeat(u64),
+    Macro(String),
+}
+
+impl FromStr for Script {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut macros: Vec<Macro> = Vec::new();
+        let mut top = Vec::new();
+        let mut open: Vec<Open> = Vec::new();
+
+        for (idx, raw) in s.lines().enumerate() {
+            let line = idx + 1;
+            let text = raw.split('#').next().unwrap().trim_end();
+            let indent = text.len() - text.trim_start().len();
+            let text = text.trim_start();
+            if text.is_empty() {
+                continue;
+            }
+
+            let token = |column: usize, found: &str, expected| ParseError::BadToken {
+                line,
+                column: indent + column,
+                found: found.to_string(),
+                expected,
+            };
+            let words: Vec<&str> = text.split(' ').collect();
+
+            match words.as_slice() {
+                ["}"] => {
+                    let block = open.pop().ok_or_else(|| token(1, "}", "a command"))?;
+                    let len = total_len(&block.body);
+                    match block.kind {
+                        Block::Repeat(times) => {
+                            let item = Item::Repeat {
+                                times,
+                                len: len.saturating_mul(times as u128),
+                                body: block.body,
+                            };
+                            innermost(&mut open, &mut top).push(item);
+                        }
+                        Block::Macro(name) => macros.push(Macro {
+                            name,
+                            body: block.body,
+                            len,
+                        }),
+                    }
+                }
+                ["repeat", times, "{"] => {
+                    let times = times
+                        .parse()
+                        .map_err(|_| token(8, times, "a number of times to repeat"))?;
+                    open.push(Open {
+                        line,
+                        kind: Block::Repeat(times),
+                        body: Vec::new(),
+                    });
+                }
+                ["macro", name, "{"] => {
+                    if !open.is_empty() {
+                        return Err(token(1, "macro", "a command, `repeat` or a macro name"));
+                    }
+                    if !is_name(name) || is_keyword(name) {
+                        return Err(token(
+                            7,
+                            name,
+                            "a macro name made of letters, digits and `_`",
+                        ));
+                    }
+                    if macros.iter().any(|m| m.name == *name) {
+                        return Err(token(7, name, "a macro name that isn't taken"));
+                    }
+                    open.push(Open {
+                        line,
+                        kind: Block::Macro(name.to_string()),
+                        body: Vec::new(),
+                    });
+                }
+                ["repeat" | "macro", ..] => {
+                    let expected = match words[0] {
+                        "repeat" => "a loop like `repeat 10 {`",
+                        _ => "a macro like `macro name {`",
+                    };
+                    return Err(ParseError::BadLine { line, expected });
+                }
+                [name] if !is_keyword(name) && is_name(name) => {
+                    let index = macros
+                        .iter()
+                        .position(|m| m.name == *name)
+                        .ok_or_else(|| token(1, name, "a command or a macro defined above"))?;
+                    let item = Item::Call {
+                        index,
+                        len: macros[index].len,
+                    };
+                    innermost(&mut open, &mut top).push(item);
+                }
+                _ => {
+                    let command = Command::parse_line(text, line).map_err(|err| match err {
+                        ParseError::BadToken {
+                            line,
+                            column,
+                            found,
+                            expected,
+                        } => ParseError::BadToken {
+                            line,
+                            column: indent + column,
+                            found,
+                            expected,
+                        },
+                        err => err,
+                    })?;
+                    innermost(&mut open, &mut top).push(Item::Command(command));
+                }
+            }
+        }
+
+        match open.pop() {
+            Some(block) => Err(ParseError::BadLine {
+                line: block.line,
+                expected: "a block that's closed with `}`",
+            }),
+            None => Ok(Self { items: top, macros }),
+        }
+    }
+}
+
+/// the body of the innermost open block, or the top level
+fn innermost<'a>(open: &'a mut [Open], top: &'a mut Vec<Item>) -> &'a mut Vec<Item> {
+    match open.last_mut() {
+        Some(block) => &mut block.body,
+        None => top,
+    }
+}
+
+fn total_len(items: &[Item]) -> u128 {
+    items
+        .iter()
+        .fold(0, |total: u128, item| total.saturating_add(item.len()))
+}
+
+fn is_name(word: &str) -> bool {
+    word.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn is_keyword(word: &str) -> bool {
+    ["forward", "up", "down", "repeat", "macro"].contains(&word)
+}
+
+impl Script {
+    /// the commands the script runs, expanded as they're read
+    pub fn commands(&self) -> Commands<'_> {
+        Commands {
+            script: self,
+            stack: vec![Frame {
+                items: &self.items,
+                next: 0,
+                repeats_left: 0,
+            }],
+        }
+    }
+
+    /// how many commands the script runs, saturating at `u128::MAX`
+    pub fn len(&self) -> u128 {
+        total_len(&self.items)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// follows the script from the start with each model side by side,
+    /// see [`navigate_models`]
+    pub fn navigate<const N: usize>(&self, models: [&dyn Navigation; N]) -> [Position; N] {
+        navigate_models(self.commands(), models)
+    }
+}
+
+/// The commands of a [`Script`], see [`Script::commands`]
+pub struct Commands<'a> {
+    script: &'a Script,
+    stack: Vec<Frame<'a>>,
+}
+
+/// A block being run
+struct Frame<'a> {
+    items: &'a [Item],
+    next: usize,
+    /// how many more times to run the block once it gets to the end
+    repeats_left: u64,
+}
+
+impl Iterator for Commands<'_> {
+    type Item = Command;
+
+    fn next(&mut self) -> Option<Command> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(item) = frame.items.get(frame.next) else {
+                if frame.repeats_left > 0 {
+                    frame.repeats_left -= 1;
+                    frame.next = 0;
+                } else {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+
+            // blocks without any commands in them are skipped, so
+            // `repeat 1000000000 {}` doesn't spin
+            if item.len() == 0 {
+                continue;
+            }
+            match item {
+                Item::Command(command) => return Some(*command),
+                Item::Repeat { times, body, .. } => self.stack.push(Frame {
+                    items: body,
+                    next: 0,
+                    repeats_left: times - 1,
+                }),
+                Item::Call { index, .. } => self.stack.push(Frame {
+                    items: &self.script.macros[*index].body,
+                    next: 0,
+                    repeats_left: 0,
+                }),
+            }
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! A small HTTP/1.1 service for solving days
+//!
+//! Written by Trevor Leibert
+//!
+//! `POST /day/<n>/part/<p>` with the input as the body answers with the
+//! solution as plain text, followed by a last line with the time it took,
+//! like `solved in 1.25 ms`. The time is also in a `Server-Timing` header.
+//! Parameters can be overridden in the (percent-encoded) query string, e.g.
+//! `/day/1/part/2?window=4`. Each connection handles a single request.
+
+use std::fmt::Display;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cancel::CancelToken;
+use crate::params::Params;
+use crate::runner::{self, format_duration};
+use crate::solution::{Context, Error};
+
+/// inputs larger than this are refused rather than read into memory
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// how long a client gets to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Something that happened to a connection, handed to the server's log
+#[derive(Debug)]
+pub enum Event {
+    /// a request was answered, `request` is its method and path
+    Answered {
+        request: String,
+        status: u16,
+        elapsed: Duration,
+    },
+    /// the connection failed before it could be answered
+    Failed(io::Error),
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Event::Answered {
+                request,
+                status,
+                elapsed,
+            } => write!(
+                f,
+                "{} -> {} ({})",
+                request,
+                status,
+                format_duration(*elapsed)
+            ),
+            Event::Failed(err) => write!(f, "connection failed: {}", err),
+        }
+    }
+}
+
+type Log = Arc<dyn Fn(&Event) + Send + Sync>;
+
+/// A listening solver service
+pub struct Server {
+    listener: TcpListener,
+    timeout: Option<Duration>,
+    log: Log,
+}
+
+impl Server {
+    /// listens on `port` of the loopback interface, 0 picks a free port.
+    /// Each solve is cancelled after `timeout`, if given. Nothing is
+    /// logged unless [`Server::with_log`] is called.
+    pub fn bind(port: u16, timeout: Option<Duration>) -> io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        Ok(Self {
+            listener,
+            timeout,
+            log: Arc::new(|_| {}),
+        })
+    }
+
+    /// tells `log` about every request answered and connection that failed,
+    /// from the connection's thread
+    pub fn with_log(mut self, log: impl Fn(&Event) + Send + Sync + 'static) -> Self {
+        self.log = Arc::new(log);
+        self
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// answers requests until the listener fails, one thread per connection
+    pub fn run(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            let timeout = self.timeout;
+            let log = Arc::clone(&self.log);
+            thread::spawn(move || {
+                if let Err(err) = handle(stream, timeout, &log) {
+                    log(&Event::Failed(err));
+                }
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// The parts of a request the service cares about
+struct Request {
+    method: String,
+    path: String,
+    query: String,
+    body: Vec<u8>,
+}
+
+struct Response {
+    status: u16,
+    headers: Vec<(&'static str, String)>,
+    body: String,
+}
+
+impl Response {
+    fn new(status: u16, body: impl Into<String>) -> Self {
+        let mut body = body.into();
+        if !body.ends_with('\n') {
+            body.push('\n');
+        }
+
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    fn header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+
+    fn write_to(&self, mut stream: impl Write) -> io::Result<()> {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: text/plain; charset=utf-8\r\n\
+             Content-Length: {}\r\nConnection: close\r\n",
+            self.status,
+            reason(self.status),
+            self.body.len()
+        );
+        for (name, value) in &self.headers {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("\r\n");
+
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(self.body.as_bytes())?;
+        stream.flush()
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+fn handle(stream: TcpStream, timeout: Option<Duration>, log: &Log) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let started = Instant::now();
+
+    let (label, response) = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => (
+            format!("{} {}", request.method, request.path),
+            route(&request, timeout),
+        ),
+        Err(response) => ("bad request".to_string(), response),
+    };
+
+    response.write_to(&stream)?;
+    log(&Event::Answered {
+        request: label,
+        status: response.status,
+        elapsed: started.elapsed(),
+    });
+    Ok(())
+}
+
+/// reads the request line, headers and body, or the response to refuse it with
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad = |msg: &str| Response::new(400, msg);
+    let mut line = String::new();
+    let mut read_line = |line: &mut String| {
+        line.clear();
+        match reader.read_line(line) {
+            Ok(0) => Err(bad("connection closed mid-request")),
+            Ok(_) => Ok(line.trim_end().to_string()),
+            Err(err) => Err(bad(&format!("couldn't read request: {}", err))),
+        }
+    };
+
+    let request_line = read_line(&mut line)?;
+    let mut words = request_line.split(' ');
+    let (method, target) = match (words.next(), words.next(), words.next()) {
+        (Some(method), Some(target), Some(version)) if version.starts_with("HTTP/1.") => {
+            (method.to_string(), target.to_string())
+        }
+        _ => return Err(bad("malformed request line")),
+    };
+
+    let mut content_length = None;
+    loop {
+        let header = read_line(&mut line)?;
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| bad("malformed header"))?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            let length = value
+                .parse()
+                .map_err(|_| bad("Content-Length isn't a number"))?;
+            content_length = Some(length);
+        } else if name.eq_ignore_ascii_case("transfer-encoding") {
+            return Err(Response::new(411, "send the input with a Content-Length"));
+        }
+    }
+
+    let length = match (content_length, method.as_str()) {
+        (Some(length), _) => length,
+        (None, "POST") => return Err(Response::new(411, "missing Content-Length")),
+        (None, _) => 0,
+    };
+    if length > MAX_BODY {
+        return Err(Response::new(
+            413,
+            format!("inputs are limited to {} bytes", MAX_BODY),
+        ));
+    }
+
+    let mut body = vec![0; length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|err| bad(&format!("couldn't read body: {}", err)))?;
+
+    let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+    Ok(Request {
+        method,
+        path: path.to_string(),
+        query: query.to_string(),
+        body,
+    })
+}
+
+/// works out which day and part a path names, `/day/<n>/part/<p>`
+fn parse_path(path: &str) -> Option<(usize, usize)> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments.as_slice() {
+        ["day", day, "part", part] => Some((day.parse().ok()?, part.parse().ok()?)),
+        _ => None,
+    }
+}
+
+fn route(request: &Request, timeout: Option<Duration>) -> Response {
+    let Some((day, part)) = parse_path(&request.path) else {
+        return Response::new(404, "expected POST /day/<n>/part/<p>");
+    };
+    if request.method != "POST" {
+        return Response::new(405, "send the input with POST").header("Allow", "POST");
+    }
+
+    match solve(day, part, request, timeout) {
+        Ok((answer, time)) => Response::new(
+            200,
+            format!("{}\nsolved in {}", answer, format_duration(time)),
+        )
+        .header(
+            "Server-Timing",
+            format!("solve;dur={:.3}", time.as_secs_f64() * 1000.0),
+        ),
+        Err(err) => Response::new(status(&err), err.to_string()),
+    }
+}
+
+/// the HTTP status a failed solve is answered with
+pub fn status(err: &Error) -> u16 {
+    match err {
+        Error::UnknownDay(_) | Error::UnknownPart(_) => 404,
+        Error::Params(_) | Error::Usage(_) => 400,
+        // no registered day leaves a part unsolved any more, so only a day
+        // fresh from `aoc new` answers this
+        Error::Unsolved => 501,
+        Error::Cancelled { .. } => 503,
+        _ => 422,
+    }
+}
+
+/// decodes `%XX` escapes, and `+` as a space like HTML forms send it
+fn percent_decode(text: &str) -> Result<String, Error> {
+    let bad = || Error::Usage(format!("`{}` isn't percent-encoded properly", text));
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'%' => {
+                let hex = rest.get(..2).ok_or_else(bad)?;
+                let hex = std::str::from_utf8(hex).map_err(|_| bad())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| bad())?);
+                rest = &rest[2..];
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| bad())
+}
+
+fn solve(
+    day: usize,
+    part: usize,
+    request: &Request,
+    timeout: Option<Duration>,
+) -> Result<(String, Duration), Error> {
+    let solution = runner::find_day(day)?;
+    let input = std::str::from_utf8(&request.body)
+        .map_err(|err| Error::Usage(format!("input isn't UTF-8: {}", err)))?;
+
+    let mut params = Params::defaults(solution.params());
+    for pair in request.query.split('&').filter(|pair| !pair.is_empty()) {
+        // decoded after splitting, so an escaped `&` or `=` stays in the value
+        let pair = match pair.split_once('=') {
+            Some((name, value)) => {
+                format!("{}={}", percent_decode(name)?, percent_decode(value)?)
+            }
+            None => percent_decode(pair)?,
+        };
+        params.set(&pair)?;
+    }
+    for spec in solution.params() {
+        if let Some(max) = spec.serve_max {
+            let value = params.usize(spec.name);
+            if value > max {
+                return Err(Error::Usage(format!(
+                    "`{}` can be at most {} when served, not {}",
+                    spec.name, max, value
+                )));
+            }
+        }
+    }
+
+    let cancel = timeout.map_or_else(CancelToken::new, CancelToken::with_timeout);
+    let ctx = Context::new(params).with_cancel(cancel);
+    let start = Instant::now();
+    let answer = runner::solve_part(solution, input, part, &ctx)?;
+    Ok((answer, start.elapsed()))
+}
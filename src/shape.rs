@@ -0,0 +1,79 @@
+//! Cheap checks that an input is shaped like a day's puzzle input
+//!
+//! Written by Trevor Leibert
+//!
+//! These only look at the layout, not whether the puzzle makes sense, so
+//! they're fast enough to run before every solve. They catch the common
+//! mistake of pointing a day at another day's input.
+
+/// fails on an input with nothing in it
+pub fn not_empty(input: &str) -> Result<(), String> {
+    if input.trim().is_empty() {
+        Err("the input is empty".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// checks every line with `valid`, describing the first one that fails as
+/// not being `expected`
+pub fn each_line(input: &str, expected: &str, valid: impl Fn(&str) -> bool) -> Result<(), String> {
+    not_empty(input)?;
+    match input
+        .lines()
+        .map(str::trim_end)
+        .enumerate()
+        .find(|(_, line)| !valid(line))
+    {
+        Some((idx, line)) => Err(format!(
+            "line {} isn't {}: `{}`",
+            idx + 1,
+            expected,
+            truncate(line)
+        )),
+        None => Ok(()),
+    }
+}
+
+/// checks the input is a rectangle of digits, returning its width and height
+pub fn digit_grid(input: &str) -> Result<(usize, usize), String> {
+    each_line(input, "a row of digits", |line| {
+        !line.is_empty() && line.bytes().all(|b| b.is_ascii_digit())
+    })?;
+    same_width(input)
+}
+
+/// checks every line is as long as the first, returning the width and height
+pub fn same_width(input: &str) -> Result<(usize, usize), String> {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let width = lines.first().map_or(0, |line| line.len());
+    match lines.iter().position(|line| line.len() != width) {
+        Some(idx) => Err(format!(
+            "line {} is {} long, but the first line is {}",
+            idx + 1,
+            lines[idx].len(),
+            width
+        )),
+        None => Ok((width, lines.len())),
+    }
+}
+
+/// true if `s` is a comma separated list of numbers
+pub fn is_number_list(s: &str) -> bool {
+    !s.is_empty() && s.split(',').all(|num| num.trim().parse::<i64>().is_ok())
+}
+
+/// true if `s` is an `x,y` pair of numbers
+pub fn is_point(s: &str) -> bool {
+    s.split_once(',').is_some_and(|(x, y)| {
+        x.trim().parse::<usize>().is_ok() && y.trim().parse::<usize>().is_ok()
+    })
+}
+
+/// keeps long lines readable in messages
+fn truncate(line: &str) -> String {
+    match line.char_indices().nth(40) {
+        Some((idx, _)) => format!("{}...", &line[..idx]),
+        None => line.to_string(),
+    }
+}
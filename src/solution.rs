@@ -0,0 +1,318 @@
+//! The interface every day's solution implements
+//!
+//! Written by Trevor Leibert
+
+use std::fmt::Display;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::cancel::CancelToken;
+use crate::checkpoint::{Checkpoint, Checkpoints, Snapshot};
+use crate::checksum::checksum;
+use crate::params::{ParamError, ParamSpec, Params};
+use crate::progress::{Progress, Reporter};
+use crate::runner::format_duration;
+
+/// Everything that can go wrong running a solution
+#[derive(Debug)]
+pub enum Error {
+    /// the part hasn't been solved yet
+    Unsolved,
+    /// no solution is registered for the day
+    UnknownDay(usize),
+    /// days only have parts 1 and 2
+    UnknownPart(usize),
+    /// the input file couldn't be read
+    Input { path: PathBuf, source: io::Error },
+    /// bad `--param` overrides
+    Params(ParamError),
+    /// a bad option on the command line
+    Usage(String),
+    /// the run was cancelled or timed out before the part finished
+    Cancelled { elapsed: Duration, progress: String },
+    /// the solver panicked, usually on malformed input
+    Panicked(String),
+    /// a checkpoint couldn't be saved or resumed from
+    Checkpoint(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Unsolved => write!(f, "not solved yet"),
+            Error::UnknownDay(day) => write!(f, "no solution for day {}", day),
+            Error::UnknownPart(part) => write!(f, "there is no part {}", part),
+            Error::Input { path, source } => {
+                write!(f, "couldn't read {}: {}", path.display(), source)
+            }
+            Error::Params(err) => write!(f, "{}", err),
+            Error::Usage(msg) => write!(f, "{}", msg),
+            Error::Cancelled { elapsed, progress } => write!(
+                f,
+                "cancelled after {} ({})",
+                format_duration(*elapsed),
+                progress
+            ),
+            Error::Panicked(reason) => write!(f, "solver panicked: {}", reason),
+            Error::Checkpoint(msg) => write!(f, "checkpoint: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParamError> for Error {
+    fn from(err: ParamError) -> Self {
+        Error::Params(err)
+    }
+}
+
+/// State handed to each part while it's solving
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub params: Params,
+    pub cancel: CancelToken,
+    pub checkpoints: Checkpoints,
+    /// told how far the slower solvers have got
+    pub progress: Option<Reporter>,
+    /// the day and part being solved, set by [`Day::run`] and [`Day::solve`]
+    pub day: usize,
+    pub part: usize,
+}
+
+impl Context {
+    pub fn new(params: Params) -> Self {
+        Self {
+            params,
+            cancel: CancelToken::new(),
+            checkpoints: Checkpoints::default(),
+            progress: None,
+            day: 0,
+            part: 0,
+        }
+    }
+
+    /// solves with a token that can cancel the run
+    pub fn with_cancel(mut self, cancel: CancelToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// solves saving or resuming checkpoints as configured
+    pub fn with_checkpoints(mut self, checkpoints: Checkpoints) -> Self {
+        self.checkpoints = checkpoints;
+        self
+    }
+
+    /// solves telling `reporter` how far the slower solvers have got
+    pub fn with_progress(mut self, reporter: Reporter) -> Self {
+        self.progress = Some(reporter);
+        self
+    }
+
+    /// reports that `done` of `total` `unit`s of work are done, if anything is listening
+    pub fn progress(&self, done: u64, total: Option<u64>, unit: &'static str) {
+        if let Some(reporter) = &self.progress {
+            reporter.report(&Progress {
+                day: self.day,
+                part: self.part,
+                done,
+                total,
+                unit,
+            });
+        }
+    }
+
+    /// the context for solving one part of a day
+    pub fn for_part(&self, day: usize, part: usize) -> Self {
+        Self {
+            day,
+            part,
+            ..self.clone()
+        }
+    }
+
+    /// the state to pick up from, if the run is resuming this part
+    pub fn resume<T: Checkpoint>(&self) -> Result<Option<Snapshot<T>>, Error> {
+        self.checkpoints.resume(self.day, self.part)
+    }
+
+    /// like [`resume`](Self::resume) for a part that stops after `steps`
+    /// steps, failing if the checkpoint was saved after that
+    pub fn resume_up_to<T: Checkpoint>(&self, steps: usize) -> Result<Option<Snapshot<T>>, Error> {
+        match self.resume()? {
+            Some(snapshot) if snapshot.step > steps => Err(Error::Checkpoint(format!(
+                "saved after step {}, but day {} part {} stops at {}",
+                snapshot.step, self.day, self.part, steps
+            ))),
+            resumed => Ok(resumed),
+        }
+    }
+
+    /// saves the state if a checkpoint is due after `step` steps. `total` is
+    /// any running total the part needs to carry on from the state.
+    pub fn checkpoint<T: Checkpoint>(
+        &self,
+        step: usize,
+        total: u64,
+        state: &T,
+    ) -> Result<(), Error> {
+        match self.checkpoints.every {
+            Some(every) if step > 0 && step.is_multiple_of(every) => {
+                self.checkpoints
+                    .save(self.day, self.part, &Snapshot { step, total, state })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// fails with [`Error::Cancelled`] if the run should stop. Solvers call this
+    /// in their loops; `progress` describes the work done so far, and is only
+    /// called if the run was cancelled.
+    pub fn check(&self, progress: impl FnOnce() -> String) -> Result<(), Error> {
+        if self.cancel.is_cancelled() {
+            Err(Error::Cancelled {
+                elapsed: self.cancel.elapsed(),
+                progress: progress(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A day's puzzle: how to parse the input, and how to solve each part
+pub trait Solution {
+    /// the day of the puzzle this solves
+    const DAY: usize;
+
+    /// the puzzle constants this day lets you override with `--param`
+    const PARAMS: &'static [ParamSpec] = &[];
+
+    /// the worked example from the puzzle text
+    const EXAMPLE: Option<Example> = None;
+
+    /// the parsed form of the puzzle input
+    type Input;
+
+    fn parse(&self, input: &str) -> Self::Input;
+
+    /// a cheap check that the input is laid out like this day's puzzle
+    /// input, to catch running against the wrong file
+    fn check_shape(&self, _input: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn part_1(&self, _input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Err(Error::Unsolved)
+    }
+
+    fn part_2(&self, _input: &Self::Input, _ctx: &Context) -> Result<String, Error> {
+        Err(Error::Unsolved)
+    }
+}
+
+/// A day's worked example from the puzzle text, with its answers
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    /// `key=value` overrides the example needs, like a smaller grid
+    pub params: &'static [&'static str],
+    pub answers: [Option<&'static str>; 2],
+}
+
+/// The answer to one part, along with how long it took
+#[derive(Debug)]
+pub struct PartRun {
+    pub answer: Result<String, Error>,
+    pub time: Duration,
+}
+
+/// The results of running both parts of a day
+#[derive(Debug)]
+pub struct Run {
+    pub day: usize,
+    /// checksum of the input the day was run against
+    pub checksum: String,
+    pub parse_time: Duration,
+    pub parts: [PartRun; 2],
+}
+
+/// Object safe view of a [`Solution`], so the runner can hold every day in one list
+pub trait Day {
+    fn day(&self) -> usize;
+
+    fn params(&self) -> &'static [ParamSpec];
+
+    fn example(&self) -> Option<Example>;
+
+    /// checks the input looks like this day's, see [`Solution::check_shape`]
+    fn check_shape(&self, input: &str) -> Result<(), String>;
+
+    /// parses the input and solves both parts, timing each step. A part
+    /// isn't started if the run was cancelled before it.
+    fn run(&self, input: &str, ctx: &Context) -> Run;
+
+    /// parses the input and solves just one part
+    fn solve(&self, input: &str, part: usize, ctx: &Context) -> Result<String, Error>;
+}
+
+impl<S: Solution> Day for S {
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn params(&self) -> &'static [ParamSpec] {
+        S::PARAMS
+    }
+
+    fn example(&self) -> Option<Example> {
+        S::EXAMPLE
+    }
+
+    fn check_shape(&self, input: &str) -> Result<(), String> {
+        Solution::check_shape(self, input)
+    }
+
+    fn run(&self, input: &str, ctx: &Context) -> Run {
+        let start = Instant::now();
+        let parsed = self.parse(input);
+        let parse_time = start.elapsed();
+
+        let time_part = |solve: &dyn Fn() -> Result<String, Error>| {
+            let start = Instant::now();
+            let answer = ctx
+                .check(|| "not started".to_string())
+                .and_then(|_| solve());
+            PartRun {
+                answer,
+                time: start.elapsed(),
+            }
+        };
+
+        let part_1 = time_part(&|| self.part_1(&parsed, &ctx.for_part(S::DAY, 1)));
+        let part_2 = time_part(&|| self.part_2(&parsed, &ctx.for_part(S::DAY, 2)));
+
+        Run {
+            day: S::DAY,
+            checksum: checksum(input),
+            parse_time,
+            parts: [part_1, part_2],
+        }
+    }
+
+    fn solve(&self, input: &str, part: usize, ctx: &Context) -> Result<String, Error> {
+        if !(1..=2).contains(&part) {
+            return Err(Error::UnknownPart(part));
+        }
+
+        let parsed = self.parse(input);
+        ctx.check(|| "not started".to_string())?;
+        let ctx = ctx.for_part(S::DAY, part);
+        match part {
+            1 => self.part_1(&parsed, &ctx),
+            _ => self.part_2(&parsed, &ctx),
+        }
+    }
+}
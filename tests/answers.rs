@@ -0,0 +1,79 @@
+//! Checks verified answers round trip and catch changed inputs
+//!
+//! Written by Trevor Leibert
+
+use std::time::Duration;
+
+use advent_of_code_2021::answers::{Answers, Verdict};
+use advent_of_code_2021::checksum::checksum;
+use advent_of_code_2021::solution::{Error, PartRun, Run};
+
+fn run(day: usize, input: &str, answers: [Result<&str, Error>; 2]) -> Run {
+    Run {
+        day,
+        checksum: checksum(input),
+        parse_time: Duration::ZERO,
+        parts: answers.map(|answer| PartRun {
+            answer: answer.map(str::to_string),
+            time: Duration::ZERO,
+        }),
+    }
+}
+
+#[test]
+fn recorded_answers_round_trip() {
+    let mut answers = Answers::default();
+    let recorded = answers.record(&run(13, "6,10\n", [Ok("17"), Ok("#.#\n\\#.")]));
+    assert_eq!(recorded, 2);
+
+    let text = answers.render();
+    assert_eq!(text.lines().count(), 3, "{}", text);
+    let reloaded = Answers::parse(&text).unwrap();
+    assert_eq!(reloaded.get(13, 1), answers.get(13, 1));
+    assert_eq!(reloaded.get(13, 2).unwrap().answer, "#.#\n\\#.");
+}
+
+#[test]
+fn unfinished_parts_are_not_recorded() {
+    let mut answers = Answers::default();
+    assert_eq!(
+        answers.record(&run(2, "up 1\n", [Err(Error::Unsolved), Ok("900")])),
+        1
+    );
+    assert!(answers.get(2, 1).is_none());
+}
+
+#[test]
+fn verdicts() {
+    let mut answers = Answers::default();
+    answers.record(&run(9, "2199943210\n", [Ok("15"), Ok("1134")]));
+
+    let same = run(9, "2199943210\n", [Ok("15"), Ok("1135")]);
+    assert_eq!(
+        answers.check(&same),
+        [
+            Verdict::Verified,
+            Verdict::Wrong {
+                expected: "1134".to_string()
+            }
+        ]
+    );
+
+    let changed = run(9, "5483143223\n", [Ok("15"), Ok("1134")]);
+    assert!(matches!(
+        answers.check(&changed),
+        [Verdict::InputChanged { .. }, Verdict::InputChanged { .. }]
+    ));
+
+    let unrecorded = run(10, "[]\n", [Ok("0"), Ok("0")]);
+    assert_eq!(
+        answers.check(&unrecorded),
+        [Verdict::Unchecked, Verdict::Unchecked]
+    );
+}
+
+#[test]
+fn malformed_lines_are_reported() {
+    let err = Answers::parse("# day part checksum answer\n9 one abc 15\n").unwrap_err();
+    assert!(err.contains("line 2"), "{}", err);
+}
@@ -0,0 +1,77 @@
+//! Solves a directory of day 6 inputs, some of them broken
+//!
+//! Written by Trevor Leibert
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use advent_of_code_2021::batch::{self, FileResult};
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::problems::problem_6::Problem6;
+use advent_of_code_2021::solution::Solution;
+
+/// a fresh directory of inputs, removed when dropped
+struct Inputs(PathBuf);
+
+impl Inputs {
+    fn new(name: &str, files: &[(&str, &str)]) -> Self {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for (name, contents) in files {
+            fs::write(dir.join(name), contents).unwrap();
+        }
+        Self(dir)
+    }
+}
+
+impl Drop for Inputs {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn answers(result: &FileResult) -> [Option<&str>; 2] {
+    let parts = result.parts.as_ref().unwrap();
+    parts.each_ref().map(|part| part.answer.as_deref().ok())
+}
+
+#[test]
+fn solves_every_file_in_name_order() {
+    let inputs = Inputs::new(
+        "order",
+        &[
+            ("b.txt", "3,4,3,1,2\n"),
+            ("a.txt", "3,4,3,1,2,1,1\n"),
+            ("broken.txt", "3,x,9\n"),
+            (".hidden", "ignored"),
+        ],
+    );
+
+    let params = Params::defaults(Problem6::PARAMS);
+    let results = batch::run_dir(&Problem6, &inputs.0, &params, None).unwrap();
+    let names: Vec<&str> = results.iter().map(|result| result.name.as_str()).collect();
+    assert_eq!(names, ["a.txt", "b.txt", "broken.txt"]);
+
+    assert_eq!(answers(&results[1]), [Some("5934"), Some("26984457539")]);
+    assert_eq!(answers(&results[2]), [None, None]);
+    assert!(results[2].shape.is_err());
+
+    let rendered = batch::render(6, &results);
+    assert!(
+        rendered.contains("| broken.txt | _failed_ | _failed_ |"),
+        "{}",
+        rendered
+    );
+    assert!(rendered.contains("## Problems"), "{}", rendered);
+    assert!(rendered.contains("Solved 2 of 3 inputs"), "{}", rendered);
+}
+
+#[test]
+fn missing_directory_is_an_error() {
+    let params = Params::defaults(Problem6::PARAMS);
+    let missing = env::temp_dir().join("aoc-batch-does-not-exist");
+    assert!(batch::run_dir(&Problem6, &missing, &params, None).is_err());
+}
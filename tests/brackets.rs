@@ -0,0 +1,50 @@
+//! Checks the day 10 scorers, streamed a line at a time
+//!
+//! Written by Trevor Leibert
+
+use std::io::{self, ErrorKind};
+
+use advent_of_code_2021::problems::problem_10::{
+    auto_complete_score, calculate_error_score, init_auto_point_map, init_err_point_map,
+};
+
+const LINES: &str = include_str!("../src/problems/examples/day_10.txt");
+
+#[test]
+fn scores_the_example() {
+    let err_point = init_err_point_map();
+    assert_eq!(
+        calculate_error_score(LINES.as_bytes(), &err_point).unwrap(),
+        26397
+    );
+
+    let auto_point = init_auto_point_map();
+    let scores = auto_complete_score(LINES.as_bytes(), &auto_point)
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(scores, [288957, 5566, 1480781, 995444, 294]);
+}
+
+#[test]
+fn completions_stream_lazily() {
+    let auto_point = init_auto_point_map();
+    // the first line is corrupted, and nothing past the second is read
+    let mut scores = auto_complete_score("(]\n[({\n!!!\n".as_bytes(), &auto_point);
+    assert_eq!(scores.next().unwrap().unwrap(), (3 * 5 + 1) * 5 + 2);
+    assert!(scores.next().unwrap().is_err());
+}
+
+#[test]
+fn rejects_characters_that_are_not_brackets() {
+    let err_point = init_err_point_map();
+    let err = calculate_error_score("()\n\n(x)\n".as_bytes(), &err_point).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 3, column 2: unexpected `x`");
+
+    let auto_point = init_auto_point_map();
+    let err = auto_complete_score("([\n<a\n".as_bytes(), &auto_point)
+        .collect::<io::Result<Vec<_>>>()
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 2, column 2: unexpected `a`");
+}
@@ -0,0 +1,67 @@
+/*
+ * Calls the solvers through the C API with the examples from the puzzle text
+ *
+ * Written by Trevor Leibert
+ */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char *DAY_1 = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+static const char *DAY_7 = "16,1,2,0,4,2,7,1,2,14\n";
+
+static int failures = 0;
+
+/* solves a part and checks both the status and the string handed back */
+static void check(uint32_t day, uint32_t part, const char *input, int32_t status,
+                  const char *expected) {
+    char *out = NULL;
+    int32_t got = aoc_solve(day, part, (const uint8_t *)input, strlen(input), &out);
+
+    if (got != status) {
+        fprintf(stderr, "day %u part %u: status %d, expected %d (%s)\n", day, part, got,
+                status, out ? out : "no message");
+        failures++;
+    } else if (out == NULL) {
+        fprintf(stderr, "day %u part %u: no string returned\n", day, part);
+        failures++;
+    } else if (expected != NULL && strcmp(out, expected) != 0) {
+        fprintf(stderr, "day %u part %u: got \"%s\", expected \"%s\"\n", day, part, out,
+                expected);
+        failures++;
+    }
+
+    aoc_string_free(out);
+}
+
+int main(void) {
+    if (aoc_abi_version() != AOC_ABI_VERSION) {
+        fprintf(stderr, "library is ABI version %u, header is %u\n", aoc_abi_version(),
+                AOC_ABI_VERSION);
+        return 1;
+    }
+
+    check(1, 1, DAY_1, AOC_OK, "7");
+    check(1, 2, DAY_1, AOC_OK, "5");
+    check(7, 1, DAY_7, AOC_OK, "37");
+    check(7, 2, DAY_7, AOC_OK, "168");
+
+    check(3, 1, "00100\n", AOC_OK, "108");
+    check(1, 3, DAY_1, AOC_UNKNOWN_PART, NULL);
+    check(26, 1, DAY_1, AOC_UNKNOWN_DAY, NULL);
+    check(1, 1, "not a depth\n", AOC_FAILED, NULL);
+    check(1, 1, "\xff\xfe", AOC_INVALID_INPUT, NULL);
+
+    if (aoc_solve(1, 1, NULL, 0, NULL) != AOC_NULL_POINTER) {
+        fprintf(stderr, "a null input wasn't rejected\n");
+        failures++;
+    }
+    aoc_string_free(NULL);
+
+    if (failures == 0) {
+        printf("ok\n");
+    }
+    return failures == 0 ? 0 : 1;
+}
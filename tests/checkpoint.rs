@@ -0,0 +1,176 @@
+//! Checks checkpoints round trip, and that resumed runs finish with the same answers
+//!
+//! Written by Trevor Leibert
+
+use std::env;
+use std::fs;
+use std::process;
+
+use advent_of_code_2021::checkpoint::{Checkpoint, Checkpoints, Saved};
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::problems;
+use advent_of_code_2021::problems::problem_11::Octopode;
+use advent_of_code_2021::problems::problem_13::Paper;
+use advent_of_code_2021::problems::problem_14::Polymer;
+use advent_of_code_2021::problems::problem_6::School;
+use advent_of_code_2021::solution::{Context, Error};
+
+const OCTOPODES: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+
+const POLYMER: &str = "NNCB\n\nCH -> B\nHH -> N\nCB -> H\nNH -> C\nHB -> C\nHC -> B\nHN -> C\nNN -> C\nBH -> H\nNC -> B\nNB -> B\nBN -> B\nBB -> N\nBC -> B\nCC -> N\nCN -> C";
+
+/// saves then loads the state, checking it comes back the same
+fn round_trip<T: Checkpoint>(state: &T) -> T {
+    let saved = state.save();
+    let loaded = T::load(&saved).unwrap();
+    assert_eq!(loaded.save(), saved);
+    loaded
+}
+
+#[test]
+fn states_round_trip() {
+    let mut octopodes = Octopode::new(OCTOPODES);
+    octopodes.step();
+    assert_eq!(round_trip(&octopodes).step(), octopodes.step());
+
+    let mut polymer: Polymer = POLYMER.parse().unwrap();
+    polymer.expand_once();
+    let mut loaded = round_trip(&polymer);
+    for _ in 0..9 {
+        polymer.expand_once();
+        loaded.expand_once();
+    }
+    assert_eq!(loaded.max_min_diff(), 1588);
+
+    let mut paper = Paper::new("6,10\n0,14\n9,10\n0,3");
+    paper.fold("fold along y=7");
+    assert_eq!(round_trip(&paper).count_dots(), paper.count_dots());
+
+    let school = School::new(&[3, 4, 3, 1, 2]);
+    assert_eq!(round_trip(&school), school);
+}
+
+#[test]
+fn malformed_states() {
+    assert_eq!(
+        School::load("1,2,3").unwrap_err(),
+        ParseError::BadLine {
+            line: 1,
+            expected: "nine comma separated fish counts"
+        }
+    );
+    assert_eq!(
+        Paper::load("#.#\n#x#").unwrap_err(),
+        ParseError::UnexpectedChar {
+            line: 2,
+            column: 2,
+            found: 'x'
+        }
+    );
+    assert!(matches!(
+        Polymer::load("chars N=2\npairs NN=1").unwrap_err(),
+        ParseError::BadLine { line: 3, .. }
+    ));
+}
+
+#[test]
+fn saved_round_trips() {
+    let saved = Saved {
+        day: 11,
+        part: 1,
+        kind: "octopode".to_string(),
+        step: 10,
+        total: 204,
+        state: OCTOPODES.to_string(),
+    };
+    assert_eq!(Saved::parse(&saved.render()).unwrap(), saved);
+    assert_eq!(
+        Saved::parse("aoc checkpoint\nday eleven").unwrap_err(),
+        ParseError::BadLine {
+            line: 2,
+            expected: "`day <n>`"
+        }
+    );
+}
+
+#[test]
+fn resumed_runs_match() {
+    let dir = env::temp_dir().join(format!("aoc-checkpoint-{}", process::id()));
+    let solution = problems::get(11).unwrap();
+    let checkpoints = Checkpoints {
+        every: Some(30),
+        dir: dir.clone(),
+        resume: None,
+    };
+    let ctx = Context::new(Params::defaults(solution.params())).with_checkpoints(checkpoints);
+    assert_eq!(solution.solve(OCTOPODES, 1, &ctx).unwrap(), "1656");
+
+    // the last checkpoint of part 1 was after 90 of its 100 steps
+    let saved = Saved::read(&dir.join("day_11_part_1.txt")).unwrap();
+    assert_eq!((saved.step, saved.kind.as_str()), (90, "octopode"));
+    for part in 1..=2 {
+        let checkpoints = Checkpoints {
+            every: None,
+            dir: dir.clone(),
+            resume: Some(Saved {
+                part,
+                ..saved.clone()
+            }),
+        };
+        let ctx = Context::new(Params::defaults(solution.params())).with_checkpoints(checkpoints);
+        let expected = if part == 1 { "1656" } else { "195" };
+        assert_eq!(solution.solve(OCTOPODES, part, &ctx).unwrap(), expected);
+    }
+
+    // day 6 can't pick up octopodes
+    let fish = problems::get(6).unwrap();
+    let checkpoints = Checkpoints {
+        every: None,
+        dir: dir.clone(),
+        resume: Some(Saved { day: 6, ..saved }),
+    };
+    let ctx = Context::new(Params::defaults(fish.params())).with_checkpoints(checkpoints);
+    assert!(matches!(
+        fish.solve("3,4,3,1,2", 1, &ctx),
+        Err(Error::Checkpoint(_))
+    ));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn checkpoints_past_the_end_are_rejected() {
+    let mut school = School::new(&[3, 4, 3, 1, 2]);
+    for _ in 0..10 {
+        school.day();
+    }
+    let saved = Saved {
+        day: 6,
+        part: 1,
+        kind: School::KIND.to_string(),
+        step: 10,
+        total: 0,
+        state: school.save(),
+    };
+
+    let fish = problems::get(6).unwrap();
+    let solve = |days: &str| {
+        let checkpoints = Checkpoints {
+            resume: Some(saved.clone()),
+            ..Checkpoints::default()
+        };
+        let args = [format!("--param=part_1_days={}", days)];
+        let params = Params::from_args(fish.params(), args).unwrap();
+        let ctx = Context::new(params).with_checkpoints(checkpoints);
+        fish.solve("3,4,3,1,2", 1, &ctx)
+    };
+    assert_eq!(solve("18").unwrap(), "26");
+    assert_eq!(solve("10").unwrap(), school.len().to_string());
+    let err = solve("5").unwrap_err();
+    assert!(matches!(err, Error::Checkpoint(_)), "{:?}", err);
+    assert_eq!(
+        err.to_string(),
+        "checkpoint: saved after step 10, but day 6 part 1 stops at 5"
+    );
+}
@@ -0,0 +1,170 @@
+//! Checks cycle detection agrees with stepping the simulations one step at a time
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::cancel::CancelToken;
+use advent_of_code_2021::cycle::{self, Cycle, Simulation};
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::problems::problem_11::{Octopode, Problem11};
+use advent_of_code_2021::problems::problem_6::{Problem6, School};
+use advent_of_code_2021::solution::{Context, Day, Error};
+use std::time::{Duration, Instant};
+
+/// solves one part of a day with `--param` overrides
+fn solve(solution: &dyn Day, input: &str, part: usize, params: &[&str]) -> String {
+    let args = params.iter().map(|pair| format!("--param={}", pair));
+    let params = Params::from_args(solution.params(), args).unwrap();
+    solution.solve(input, part, &Context::new(params)).unwrap()
+}
+
+/// a context that's never cancelled
+fn ctx() -> Context {
+    Context::new(Params::defaults(&[]))
+}
+
+const OCTOPODES: &str = "5483143223\n2745854711\n5264556173\n6141336146\n6357385478\n4167524645\n2176841721\n6882881134\n4846848554\n5283751526";
+
+/// A counter that runs up to `start` then loops round `len` values
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Rho {
+    at: u64,
+    start: u64,
+    len: u64,
+}
+
+impl Simulation for Rho {
+    fn advance(&mut self) -> u64 {
+        self.at += 1;
+        if self.at == self.start + self.len {
+            self.at = self.start;
+        }
+        self.at
+    }
+}
+
+#[test]
+fn finds_cycles() {
+    for start in 0..20 {
+        for len in 1..20 {
+            let rho = Rho { at: 0, start, len };
+            assert_eq!(
+                cycle::find_cycle(&rho, &ctx()).unwrap(),
+                Cycle { start, len }
+            );
+
+            let mut stepped = rho.clone();
+            let mut total = 0;
+            for steps in 0..100 {
+                assert_eq!(
+                    cycle::after(&rho, steps, &ctx()).unwrap(),
+                    (stepped.clone(), total)
+                );
+                total += stepped.advance();
+            }
+        }
+    }
+}
+
+#[test]
+fn octopodes() {
+    let octopodes = Octopode::new(OCTOPODES);
+    let cycle = cycle::find_cycle(&octopodes, &ctx()).unwrap();
+    assert_eq!(cycle.len, 10);
+    assert!(cycle.start <= 195);
+
+    let mut stepped = octopodes.clone();
+    let mut flashes = 0;
+    for _ in 0..1000 {
+        flashes += stepped.step() as u64;
+    }
+    assert_eq!(cycle::after(&octopodes, 100, &ctx()).unwrap().1, 1656);
+    assert_eq!(
+        cycle::after(&octopodes, 1000, &ctx()).unwrap(),
+        (stepped, flashes)
+    );
+
+    // after synchronizing, all 100 flash together every 10 steps
+    let far = 1_000_000_000_000;
+    assert_eq!(
+        cycle::after(&octopodes, far, &ctx()).unwrap().1,
+        flashes + (far - 1000) / 10 * 100
+    );
+}
+
+#[test]
+fn fish_modulo() {
+    let mut school = School::new(&[3, 4, 3, 1, 2]);
+    let modulo = school.modulo(100);
+    assert_eq!(cycle::find_cycle(&modulo, &ctx()).unwrap().start, 0);
+
+    for days in 0..300 {
+        assert_eq!(
+            cycle::after(&modulo, days, &ctx()).unwrap().0.len(),
+            school.len() as u64 % 100
+        );
+        school.day();
+    }
+    assert_eq!(
+        cycle::after(&modulo, 256, &ctx()).unwrap().0.len(),
+        26984457539 % 100
+    );
+    assert!(
+        cycle::after(&modulo, 1_000_000_000_000, &ctx())
+            .unwrap()
+            .0
+            .len()
+            < 100
+    );
+}
+
+#[test]
+fn solvers_skip_ahead() {
+    // 1656 flashes in the first 100 steps, then every 10 steps all 100 flash
+    // together from the synchronized flash at step 195 on
+    let far = 1_000_000_000_000u64;
+    let flashes: u64 = solve(&Problem11, OCTOPODES, 1, &["steps=1000"])
+        .parse()
+        .unwrap();
+    assert_eq!(solve(&Problem11, OCTOPODES, 1, &["steps=100"]), "1656");
+    assert_eq!(
+        solve(&Problem11, OCTOPODES, 1, &["steps=1000000000000"]),
+        (flashes + (far - 1000) / 10 * 100).to_string()
+    );
+
+    let fish = "3,4,3,1,2";
+    assert_eq!(solve(&Problem6, fish, 2, &[]), "26984457539");
+    assert_eq!(solve(&Problem6, fish, 2, &["modulus=1000"]), "539");
+    let far_off = solve(
+        &Problem6,
+        fish,
+        2,
+        &["modulus=100", "part_2_days=1000000000000"],
+    );
+    assert!(far_off.parse::<u64>().unwrap() < 100);
+}
+
+#[test]
+fn skipping_ahead_can_be_cancelled() {
+    let cancel = CancelToken::new();
+    cancel.cancel();
+    let ctx = ctx().with_cancel(cancel);
+    let rho = Rho {
+        at: 0,
+        start: 0,
+        len: u64::MAX,
+    };
+    let err = cycle::find_cycle(&rho, &ctx).unwrap_err();
+    assert!(matches!(err, Error::Cancelled { .. }), "{:?}", err);
+
+    // counting modulo a large prime takes far too long to repeat
+    let args = ["modulus=1000000007", "part_2_days=1000000000000000000"]
+        .iter()
+        .map(|pair| format!("--param={}", pair));
+    let params = Params::from_args(Problem6.params(), args).unwrap();
+    let ctx =
+        Context::new(params).with_cancel(CancelToken::with_timeout(Duration::from_millis(20)));
+    let started = Instant::now();
+    let err = Problem6.solve("3,4,3,1,2", 2, &ctx).unwrap_err();
+    assert!(matches!(err, Error::Cancelled { .. }), "{:?}", err);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
@@ -0,0 +1,170 @@
+//! Checks the day 3 diagnostic report
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::problems::problem_3::{BitCounts, BitTrie, DiagnosticReport};
+
+const REPORT: &str = include_str!("../src/problems/examples/day_3.txt");
+
+#[test]
+fn example_ratings() {
+    let report: DiagnosticReport = REPORT.parse().unwrap();
+    assert_eq!(report.width(), 5);
+    assert_eq!(report.counts().ones, [7, 5, 8, 7, 5]);
+    assert_eq!((report.gamma(), report.epsilon()), (22, 9));
+    assert_eq!(report.power_consumption(), 198);
+    assert_eq!((report.oxygen_rating(), report.co2_rating()), (23, 10));
+    assert_eq!(report.life_support_rating(), 230);
+
+    let streamed = BitCounts::from_reader(REPORT.as_bytes()).unwrap();
+    assert_eq!(&streamed, report.counts());
+
+    let text = report.to_string();
+    assert!(text.contains("     1      7      5   58.3  1"), "{}", text);
+    assert!(text.contains("Gamma 10110 (22), epsilon 01001 (9), power consumption 198"));
+    assert!(text.contains("Oxygen generator 23, CO2 scrubber 10, life support 230"));
+}
+
+#[test]
+fn counts_streamed_reports() {
+    let counts = BitCounts::from_reader("\n101\n\n111\n001\n".as_bytes()).unwrap();
+    assert_eq!(counts.ones, [2, 1, 3]);
+    assert_eq!(counts.lines, 3);
+    assert_eq!(counts.zeros(1), 2);
+    assert_eq!((counts.gamma(), counts.epsilon()), (0b101, 0b010));
+
+    let empty = BitCounts::from_reader("".as_bytes()).unwrap();
+    assert_eq!((empty.lines, empty.gamma(), empty.epsilon()), (0, 0, 0));
+
+    // the line number counts the blank lines too
+    let err = BitCounts::from_reader("101\n\n\n11\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 4 has 2 bits, expected 3");
+
+    let err = BitCounts::from_reader("101\n1x1\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 2, column 2: unexpected `x`");
+    let err = BitCounts::from_reader("2\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let wide = "1".repeat(65);
+    let err = BitCounts::from_reader(wide.as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    let widest = BitCounts::from_reader("1".repeat(64).as_bytes()).unwrap();
+    assert_eq!((widest.gamma(), widest.epsilon()), (usize::MAX, 0));
+
+    // counts built by hand can still be wider than a usize
+    let counts = BitCounts {
+        ones: vec![0; 70],
+        lines: 1,
+    };
+    assert_eq!((counts.gamma(), counts.epsilon()), (0, usize::MAX));
+}
+
+#[test]
+fn odd_and_tiny_reports() {
+    // 3 lines, where the old `count / (lines / 2)` said 1 of 3 ones was common
+    let report: DiagnosticReport = "100\n010\n001\n".parse().unwrap();
+    assert_eq!((report.gamma(), report.epsilon()), (0b000, 0b111));
+
+    // a single line is its own most common bits
+    let report: DiagnosticReport = "00100\n".parse().unwrap();
+    assert_eq!((report.gamma(), report.epsilon()), (4, 27));
+    assert_eq!(report.life_support_rating(), 16);
+
+    // ties count as ones
+    let report: DiagnosticReport = "10\n01\n".parse().unwrap();
+    assert_eq!((report.gamma(), report.epsilon()), (0b11, 0b00));
+    assert_eq!((report.oxygen_rating(), report.co2_rating()), (0b10, 0b01));
+
+    // duplicates that survive every column
+    let report: DiagnosticReport = "11\n11\n00\n".parse().unwrap();
+    assert_eq!((report.oxygen_rating(), report.co2_rating()), (0b11, 0b00));
+}
+
+#[test]
+fn malformed_reports() {
+    let err = |report: &str| report.parse::<DiagnosticReport>().unwrap_err();
+    assert_eq!(err("\n\n"), ParseError::Empty);
+    assert_eq!(
+        err("101\n1x1\n"),
+        ParseError::UnexpectedChar {
+            line: 2,
+            column: 2,
+            found: 'x'
+        }
+    );
+    assert_eq!(
+        err("101\n\n10\n"),
+        ParseError::Ragged {
+            line: 3,
+            width: 2,
+            expected: 3
+        }
+    );
+}
+
+/// xorshift, so the random reports are the same every run
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+#[test]
+fn trie_agrees_with_filtering() {
+    let report: DiagnosticReport = REPORT.parse().unwrap();
+    let trie = report.trie();
+    assert_eq!(trie.len(), 12);
+    assert_eq!(
+        (trie.oxygen_rating(), trie.co2_rating()),
+        (Some(23), Some(10))
+    );
+    assert_eq!(BitTrie::new(5).life_support_rating(), None);
+
+    let mut rng = Rng(0x2021_0003);
+    for _ in 0..500 {
+        let width = 1 + rng.below(16) as usize;
+        let lines = 1 + rng.below(200);
+        // a narrow range of values now and then, for plenty of duplicates
+        // and shared prefixes
+        let range = match rng.below(3) {
+            0 => (1 + rng.below(4)).min(1 << width),
+            _ => 1 << width,
+        };
+        let text: String = (0..lines)
+            .map(|_| format!("{:0width$b}\n", rng.below(range), width = width))
+            .collect();
+
+        let report: DiagnosticReport = text.parse().unwrap();
+        let trie = report.trie();
+        assert_eq!(
+            trie.oxygen_rating(),
+            Some(report.oxygen_rating()),
+            "{}",
+            text
+        );
+        assert_eq!(trie.co2_rating(), Some(report.co2_rating()), "{}", text);
+    }
+}
+
+#[test]
+fn trie_scales() {
+    let mut rng = Rng(12);
+    let mut trie = BitTrie::new(20);
+    for _ in 0..1_000_000 {
+        trie.insert(rng.below(1 << 20) as usize);
+    }
+    assert_eq!(trie.len(), 1_000_000);
+    assert!(trie.life_support_rating().is_some());
+}
@@ -0,0 +1,83 @@
+//! Checks every day solves its embedded example, and how example answers are compared
+//!
+//! Written by Trevor Leibert
+
+use std::time::Duration;
+
+use advent_of_code_2021::answers::Verdict;
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::problems::DAYS;
+use advent_of_code_2021::runner;
+use advent_of_code_2021::solution::{Context, Error, Example, PartRun, Run};
+
+#[test]
+fn every_day_solves_its_example() {
+    for solution in DAYS {
+        let example = solution.example().unwrap();
+        let args = example
+            .params
+            .iter()
+            .map(|pair| format!("--param={}", pair));
+        let params = Params::from_args(solution.params(), args).unwrap();
+        // day 9's puzzle inputs are always 100x100, but its example is far smaller
+        match solution.day() {
+            9 => assert!(solution.check_shape(example.input).is_err()),
+            _ => assert_eq!(solution.check_shape(example.input), Ok(())),
+        }
+
+        let run = solution.run(example.input, &Context::new(params));
+        assert_eq!(
+            runner::check_example(&example, &run),
+            [1, 2].map(|part| match run.parts[part - 1].answer {
+                Err(Error::Unsolved) => Verdict::Unchecked,
+                _ => Verdict::Verified,
+            }),
+            "day {}",
+            solution.day()
+        );
+    }
+}
+
+#[test]
+fn compares_with_the_example() {
+    let example = Example {
+        input: "",
+        params: &[],
+        answers: [Some("7"), Some("5")],
+    };
+    let part = |answer| PartRun {
+        answer,
+        time: Duration::ZERO,
+    };
+    let run = |part_1, part_2| Run {
+        day: 1,
+        checksum: String::new(),
+        parse_time: Duration::ZERO,
+        parts: [part(part_1), part(part_2)],
+    };
+
+    assert_eq!(
+        runner::check_example(&example, &run(Ok("7".to_string()), Ok("6".to_string()))),
+        [
+            Verdict::Verified,
+            Verdict::Wrong {
+                expected: "5".to_string()
+            }
+        ]
+    );
+    assert_eq!(
+        runner::check_example(
+            &example,
+            &run(
+                Err(Error::Unsolved),
+                Err(Error::Panicked("oops".to_string()))
+            )
+        ),
+        [
+            Verdict::Unchecked,
+            Verdict::Wrong {
+                expected: "5".to_string()
+            }
+        ]
+    );
+}
@@ -0,0 +1,90 @@
+//! Checks the C API by compiling and running a C program against the cdylib
+//!
+//! Written by Trevor Leibert
+#![cfg(unix)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use advent_of_code_2021::ffi::{self, AOC_FAILED, AOC_UNKNOWN_PART, AOC_UNSOLVED};
+use advent_of_code_2021::solution::Error;
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// the directory cargo put the cdylib in, next to the test binary
+fn library_dir() -> PathBuf {
+    let exe = env::current_exe().unwrap();
+    exe.parent().unwrap().to_path_buf()
+}
+
+#[test]
+fn checked_in_header_is_current() {
+    let generated = Path::new(env!("OUT_DIR")).join("aoc.h");
+    let checked_in = manifest_dir().join("include").join("aoc.h");
+
+    let expected = fs::read_to_string(&generated).unwrap();
+    let actual = fs::read_to_string(&checked_in).unwrap_or_default();
+    assert!(
+        expected == actual,
+        "{} is out of date with src/ffi.rs, regenerate it with\n    cp {} {}",
+        checked_in.display(),
+        generated.display(),
+        checked_in.display()
+    );
+}
+
+#[test]
+fn errors_map_to_statuses() {
+    assert_eq!(ffi::status(&Error::Unsolved), AOC_UNSOLVED);
+    assert_eq!(ffi::status(&Error::UnknownPart(3)), AOC_UNKNOWN_PART);
+    assert_eq!(ffi::status(&Error::Usage("bad".to_string())), AOC_FAILED);
+}
+
+#[test]
+fn c_program_solves_examples() {
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let lib_dir = library_dir();
+    let library = lib_dir.join(format!(
+        "{}advent_of_code_2021{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    ));
+    assert!(library.exists(), "no cdylib at {}", library.display());
+
+    let program = lib_dir.join("ffi_solve");
+    let compiled = Command::new(&cc)
+        .arg(manifest_dir().join("tests").join("c").join("solve.c"))
+        .arg("-I")
+        .arg(manifest_dir().join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-ladvent_of_code_2021")
+        .arg("-o")
+        .arg(&program)
+        .output()
+        .unwrap_or_else(|err| {
+            panic!(
+                "couldn't run the C compiler `{}`: {}\n\
+                 install one, or point `CC` at it",
+                cc, err
+            )
+        });
+    assert!(
+        compiled.status.success(),
+        "compiling tests/c/solve.c failed:\n{}",
+        String::from_utf8_lossy(&compiled.stderr)
+    );
+
+    let run = Command::new(&program).output().unwrap();
+    assert!(
+        run.status.success(),
+        "tests/c/solve.c failed:\n{}",
+        String::from_utf8_lossy(&run.stderr)
+    );
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "ok\n");
+}
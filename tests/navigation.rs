@@ -0,0 +1,170 @@
+//! Checks the day 2 navigation models, and plugging in new ones
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::problems::problem_2::{
+    navigate_with, retrace, Aim, Command, Corruption, Direct, Instructions, Navigation, Position,
+    Reversible, Trajectory,
+};
+
+const COURSE: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+/// a sub that's stuck on the surface, where up and down do nothing
+struct Surface;
+
+impl Navigation for Surface {
+    fn apply(&self, position: &mut Position, command: Command) {
+        if let Command::Forward(quantity) = command {
+            position.horizontal += quantity;
+        }
+    }
+}
+
+#[test]
+fn both_answers_from_one_run() {
+    let instructions: Instructions = COURSE.parse().unwrap();
+    let [direct, aim] = instructions.navigate([&Direct, &Aim]);
+    assert_eq!(direct, Position::new(15, 10, 0));
+    assert_eq!(aim, Position::new(15, 60, 10));
+    assert_eq!((direct.product(), aim.product()), (150, 900));
+
+    let streamed = navigate_with(COURSE.as_bytes(), [&Direct, &Aim]).unwrap();
+    assert_eq!(streamed, [direct, aim]);
+}
+
+#[test]
+fn custom_models() {
+    let instructions: Instructions = COURSE.parse().unwrap();
+    let [surface, direct] = instructions.navigate([&Surface, &Direct]);
+    assert_eq!(surface, Position::new(15, 0, 0));
+    assert_eq!(direct.product(), 150);
+}
+
+#[test]
+fn trajectory() {
+    let instructions: Instructions = COURSE.parse().unwrap();
+    let trajectory = instructions.trajectory(&Aim);
+    assert_eq!(trajectory.len(), 6);
+    assert_eq!(trajectory.position(), instructions.navigate([&Aim])[0]);
+
+    let csv = trajectory.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "step,command,horizontal,depth,aim");
+    assert_eq!(lines[1], "0,,0,0,0");
+    assert_eq!(lines[3], "2,down 5,5,0,5");
+    assert_eq!(lines[7], "6,forward 2,15,60,10");
+
+    let summary = trajectory.summary();
+    assert_eq!((summary.max_depth, summary.max_depth_step), (60, 6));
+    // 5 across, then 8 across and 40 down, then 2 across and 20 down
+    let expected = 5.0 + 8f64.hypot(40.0) + 2f64.hypot(20.0);
+    assert!((summary.total_distance - expected).abs() < 1e-9);
+
+    let still = Trajectory::new(Position::new(3, 4, 0)).summary();
+    assert_eq!((still.max_depth, still.max_depth_step), (4, 0));
+    assert_eq!(still.total_distance, 0.0);
+}
+
+#[test]
+fn retraces_to_the_start() {
+    let instructions: Instructions = COURSE.parse().unwrap();
+    let start = Position::new(-4, 7, 3);
+    let models: [&dyn Reversible; 2] = [&Direct, &Aim];
+    for model in models {
+        let end =
+            Trajectory::record(model, start, instructions.commands().iter().copied()).position();
+        assert_eq!(instructions.retrace(model, end), start);
+    }
+
+    let commands = ["down 2", "forward 3"].map(|line| line.parse::<Command>().unwrap());
+    assert_eq!(
+        retrace(&Aim, Position::new(3, 6, 2), commands.into_iter()),
+        Position::default()
+    );
+}
+
+#[test]
+fn finds_corrupted_commands() {
+    let instructions: Instructions = COURSE.parse().unwrap();
+    let start = Position::default();
+
+    // `forward 8` came through as `forward 9`
+    assert_eq!(
+        instructions.find_corruption(&Aim, start, Position::new(16, 65, 10)),
+        [Corruption {
+            index: 2,
+            found: Command::Forward(8),
+            fix: Command::Forward(9)
+        }]
+    );
+
+    // the aim was 2 lower from the middle on, which either turn explains
+    assert_eq!(
+        instructions.find_corruption(&Aim, start, Position::new(15, 56, 8)),
+        [
+            Corruption {
+                index: 3,
+                found: Command::Up(3),
+                fix: Command::Up(5)
+            },
+            Corruption {
+                index: 4,
+                found: Command::Down(8),
+                fix: Command::Down(6)
+            }
+        ]
+    );
+
+    // any of the turns could have been 4 deeper
+    let fixes: Vec<(usize, Command)> = instructions
+        .find_corruption(&Direct, start, Position::new(15, 14, 0))
+        .into_iter()
+        .map(|corruption| (corruption.index, corruption.fix))
+        .collect();
+    assert_eq!(
+        fixes,
+        [
+            (1, Command::Down(9)),
+            (3, Command::Down(1)),
+            (4, Command::Down(12))
+        ]
+    );
+
+    // nothing to explain, or more than one command changed
+    let end = instructions.navigate([&Aim])[0];
+    assert!(instructions.find_corruption(&Aim, start, end).is_empty());
+    assert!(instructions
+        .find_corruption(&Aim, start, Position::new(100, 100, 100))
+        .is_empty());
+}
+
+#[test]
+fn zero_commands_only_fix_real_corruption() {
+    let instructions: Instructions = "forward 5\nforward 0\ndown 5\nforward 8\nup 0\n"
+        .parse()
+        .unwrap();
+    let start = Position::default();
+
+    // `down 0` or `forward 0` in place of a zero command changes nothing
+    for model in [&Aim as &dyn Reversible, &Direct] {
+        let end = instructions.navigate([model])[0];
+        assert!(instructions.find_corruption(model, start, end).is_empty());
+    }
+
+    // `forward 0` came through in place of `down 2`
+    assert_eq!(
+        instructions.find_corruption(&Aim, start, Position::new(13, 56, 7)),
+        [
+            Corruption {
+                index: 1,
+                found: Command::Forward(0),
+                fix: Command::Down(2)
+            },
+            Corruption {
+                index: 2,
+                found: Command::Down(5),
+                fix: Command::Down(7)
+            }
+        ]
+    );
+}
@@ -0,0 +1,189 @@
+//! Checks the puzzle types parse with `str::parse` and report typed errors
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::problems::problem_11::Octopode;
+use advent_of_code_2021::problems::problem_12::Graph;
+use advent_of_code_2021::problems::problem_13::Paper;
+use advent_of_code_2021::problems::problem_14::Polymer;
+use advent_of_code_2021::problems::problem_2::{
+    navigate, Aim, Command, Direct, Instructions, Position,
+};
+use advent_of_code_2021::problems::problem_4::Board;
+use advent_of_code_2021::problems::problem_9::HeightMap;
+
+const BOARD: &str =
+    "22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19";
+
+#[test]
+fn board() {
+    let mut board: Board = BOARD.parse().unwrap();
+    assert!(!board.play(22));
+
+    assert_eq!(
+        "1 2 3\n4 5 6".parse::<Board>().unwrap_err(),
+        ParseError::WrongSize {
+            expected: (5, 5),
+            found: (3, 2)
+        }
+    );
+    assert_eq!(
+        BOARD.replace("14", "xx").parse::<Board>().unwrap_err(),
+        ParseError::BadLine {
+            line: 3,
+            expected: "a row of numbers"
+        }
+    );
+    assert_eq!("\n".parse::<Board>().unwrap_err(), ParseError::Empty);
+}
+
+#[test]
+fn paper() {
+    let paper: Paper = "6,10\n0,14\n9,10".parse().unwrap();
+    assert_eq!(paper.count_dots(), 3);
+    assert_eq!((paper.x_len(), paper.y_len()), (10, 15));
+
+    assert_eq!(
+        "6,10\nfold along y=7".parse::<Paper>().unwrap_err(),
+        ParseError::BadLine {
+            line: 2,
+            expected: "a dot like `6,10`"
+        }
+    );
+    assert_eq!("".parse::<Paper>().unwrap_err(), ParseError::Empty);
+}
+
+#[test]
+fn height_map() {
+    let map: HeightMap = "219\n398".parse().unwrap();
+    assert_eq!(map.get(1, 1), 9);
+
+    assert_eq!(
+        "219\n3a8".parse::<HeightMap>().unwrap_err(),
+        ParseError::UnexpectedChar {
+            line: 2,
+            column: 2,
+            found: 'a'
+        }
+    );
+    assert_eq!(
+        "219\n39".parse::<HeightMap>().unwrap_err(),
+        ParseError::Ragged {
+            line: 2,
+            width: 2,
+            expected: 3
+        }
+    );
+}
+
+#[test]
+fn octopode() {
+    let mut octopodes: Octopode = "11111\n19991\n19191\n19991\n11111".parse().unwrap();
+    assert_eq!(octopodes.step(), 9);
+    assert!("".parse::<Octopode>().is_err());
+}
+
+#[test]
+fn graph_owns_its_names() {
+    let graph: Graph<'static> = {
+        let text = String::from("start-A\nA-end\nA-b");
+        text.parse().unwrap()
+    };
+    assert_eq!(graph.len(), 4);
+    assert!(graph.get_neighbors("A").contains("end"));
+
+    assert_eq!(
+        "start-A\nA end".parse::<Graph>().unwrap_err(),
+        ParseError::BadLine {
+            line: 2,
+            expected: "a link like `start-A`"
+        }
+    );
+}
+
+#[test]
+fn borrowed_graph_matches_owned() {
+    let text = "start-A\nA-end\nA-b";
+    let borrowed = Graph::try_new(text).unwrap();
+    let owned: Graph = text.parse().unwrap();
+    assert_eq!(format!("{:?}", borrowed), format!("{:?}", owned));
+}
+
+#[test]
+fn polymer() {
+    let mut polymer: Polymer = "NNCB\n\nCH -> B\nNN -> C\nNC -> B\nCB -> H"
+        .parse()
+        .unwrap();
+    polymer.expand_once();
+    assert_eq!(polymer.max_min_diff(), 1);
+
+    assert_eq!(
+        "NNCB\n\nCH -> B\nNN > C".parse::<Polymer>().unwrap_err(),
+        ParseError::BadLine {
+            line: 4,
+            expected: "a rule like `CH -> B`"
+        }
+    );
+    assert_eq!(
+        "NnCB\n\nCH -> B".parse::<Polymer>().unwrap_err(),
+        ParseError::UnexpectedChar {
+            line: 1,
+            column: 2,
+            found: 'n'
+        }
+    );
+}
+
+#[test]
+fn commands() {
+    assert_eq!("forward 5".parse(), Ok(Command::Forward(5)));
+    assert_eq!("down 8\n".parse::<Command>().unwrap().to_string(), "down 8");
+
+    let course = "forward 5\ndown 5\nfoward 8\nup 3";
+    assert_eq!(
+        course.parse::<Instructions>().unwrap_err(),
+        ParseError::BadToken {
+            line: 3,
+            column: 1,
+            found: "foward".to_string(),
+            expected: "`forward`, `up` or `down`"
+        }
+    );
+    assert_eq!(
+        "up 3\ndown five".parse::<Instructions>().unwrap_err(),
+        ParseError::BadToken {
+            line: 2,
+            column: 6,
+            found: "five".to_string(),
+            expected: "a whole number"
+        }
+    );
+    assert_eq!(
+        "forward".parse::<Command>().unwrap_err(),
+        ParseError::BadLine {
+            line: 1,
+            expected: "a command like `forward 5`"
+        }
+    );
+
+    // a typo no longer moves the sub
+    let err = navigate(course.as_bytes()).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+    let instructions: Instructions = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n"
+        .parse()
+        .unwrap();
+    assert_eq!(instructions.len(), 6);
+    let mut position = Position::new(0, 0, 0);
+    instructions.apply(&Aim, &mut position);
+    assert_eq!(position.product(), 900);
+    let mut position = Position::new(0, 0, 0);
+    instructions.apply(&Direct, &mut position);
+    assert_eq!(position.product(), 150);
+
+    let mut position = Position::default();
+    position.update(&Direct, "down 4");
+    position.update(&Aim, "forward 2");
+    assert_eq!(position, Position::new(2, 4, 0));
+}
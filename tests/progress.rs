@@ -0,0 +1,94 @@
+//! Checks the slower solvers report their progress, and how it's rendered
+//!
+//! Written by Trevor Leibert
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use advent_of_code_2021::params::Params;
+use advent_of_code_2021::problems;
+use advent_of_code_2021::progress::{self, Progress, Reporter};
+use advent_of_code_2021::solution::Context;
+
+/// solves a part, collecting everything it reports
+fn reports(day: usize, part: usize, input: &str) -> Vec<Progress> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let reporter = {
+        let seen = Arc::clone(&seen);
+        Reporter::new(move |progress| seen.lock().unwrap().push(*progress))
+    };
+    let solution = problems::get(day).unwrap();
+    let ctx = Context::new(Params::defaults(solution.params())).with_progress(reporter);
+    solution.solve(input, part, &ctx).unwrap();
+
+    let seen = seen.lock().unwrap();
+    seen.clone()
+}
+
+#[test]
+fn paths() {
+    let caves = "start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end";
+    let seen = reports(12, 2, caves);
+    assert_eq!(seen.len(), 36);
+    assert_eq!(
+        seen.last(),
+        Some(&Progress {
+            day: 12,
+            part: 2,
+            done: 36,
+            total: None,
+            unit: "paths"
+        })
+    );
+}
+
+#[test]
+fn positions() {
+    let seen = reports(7, 2, "16,1,2,0,4,2,7,1,2,14");
+    assert_eq!(seen.len(), 18);
+    assert!(seen.iter().all(|progress| progress.total == Some(17)));
+    // the last report says it's finished
+    assert_eq!(seen.last().unwrap().done, 17);
+}
+
+#[test]
+fn steps() {
+    let octopodes = include_str!("../src/problems/examples/day_11.txt");
+    let seen = reports(11, 2, octopodes);
+    assert_eq!(seen.len(), 195);
+    assert!(seen
+        .iter()
+        .zip(1..)
+        .all(|(progress, step)| progress.done == step));
+    assert_eq!(
+        seen.last(),
+        Some(&Progress {
+            day: 11,
+            part: 2,
+            done: 195,
+            total: None,
+            unit: "steps"
+        })
+    );
+}
+
+#[test]
+fn render() {
+    let mut progress = Progress {
+        day: 11,
+        part: 2,
+        done: 150,
+        total: None,
+        unit: "steps",
+    };
+    assert_eq!(
+        progress::render(&progress, Duration::from_secs(2)),
+        "day 11 part 2: 150 steps, 75 steps/s, 2.00 s"
+    );
+
+    progress.total = Some(600);
+    assert_eq!(
+        progress::render(&progress, Duration::from_millis(500)),
+        "day 11 part 2: 150 of 600 steps (25%), 300 steps/s, 500.00 ms"
+    );
+}
@@ -0,0 +1,47 @@
+//! Snapshots of the user-facing renders of the puzzle types
+//!
+//! Written by Trevor Leibert
+
+mod snapshot;
+
+use advent_of_code_2021::problems::problem_12::Graph;
+use advent_of_code_2021::problems::problem_13::Paper;
+use advent_of_code_2021::problems::problem_4::Board;
+use snapshot::assert_snapshot;
+
+#[test]
+fn board_display() {
+    let mut board = Board::new(
+        0,
+        "14 21 17 24  4\n\
+         10 16 15  9 19\n\
+         18  8 23 26 20\n\
+         22 11 13  6  5\n\
+          2  0 12  3  7",
+    );
+    for num in [7, 4, 9, 5, 11, 17, 23, 2, 0, 14, 21, 24] {
+        board.play(num);
+    }
+
+    assert_snapshot("board_display", &board.to_string());
+}
+
+#[test]
+fn paper_debug() {
+    let mut paper = Paper::new(
+        "6,10\n0,14\n9,10\n0,3\n10,4\n4,11\n6,0\n6,12\n4,1\n\
+         0,13\n10,12\n3,4\n3,0\n8,4\n1,10\n2,14\n8,10\n9,0",
+    );
+    assert_snapshot("paper_debug_unfolded", &format!("{:?}", paper));
+
+    paper.fold("fold along y=7");
+    paper.fold("fold along x=5");
+    assert_snapshot("paper_debug_folded", &format!("{:?}", paper));
+}
+
+#[test]
+fn graph_debug() {
+    let graph = Graph::new("start-A\nstart-b\nA-c\nA-b\nb-d\nA-end\nb-end");
+
+    assert_snapshot("graph_debug", &format!("{:?}", graph));
+}
@@ -0,0 +1,54 @@
+//! Checks how the report renders each day's outcome
+//!
+//! Written by Trevor Leibert
+
+use std::path::PathBuf;
+use std::time::Duration;
+use std::{io, panic};
+
+use advent_of_code_2021::report::{self, DayReport};
+use advent_of_code_2021::runner::catch_panic;
+use advent_of_code_2021::solution::{Error, PartRun, Run};
+
+#[test]
+fn panics_are_failed_rows() {
+    panic::set_hook(Box::new(|_| {}));
+    let panicked = catch_panic::<Run>(|| panic!("bad input"));
+    let _ = panic::take_hook();
+
+    let part = |answer: &str| PartRun {
+        answer: Ok(answer.to_string()),
+        time: Duration::ZERO,
+    };
+    let reports = [
+        DayReport {
+            day: 1,
+            result: Ok(Run {
+                day: 1,
+                checksum: "abc".to_string(),
+                parse_time: Duration::ZERO,
+                parts: [part("7"), part("5")],
+            }),
+        },
+        DayReport {
+            day: 2,
+            result: panicked,
+        },
+        DayReport {
+            day: 3,
+            result: Err(Error::Input {
+                path: PathBuf::from("inputs/input_3.txt"),
+                source: io::ErrorKind::NotFound.into(),
+            }),
+        },
+    ];
+
+    let markdown = report::render(&reports);
+    assert!(markdown.contains("| Part 2 | 5 |"), "{}", markdown);
+    assert!(
+        markdown.contains("## Day 2\n\n| Step | Answer | Time |\n| --- | --- | ---: |\n| Run | _solver panicked: bad input_ | |\n"),
+        "{}",
+        markdown
+    );
+    assert!(markdown.contains("## Day 3\n\nSkipped: "), "{}", markdown);
+}
@@ -0,0 +1,134 @@
+//! Scaffolds new days into a copy of the registry
+//!
+//! Written by Trevor Leibert
+
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use advent_of_code_2021::scaffold;
+
+const REGISTRY: &str = include_str!("../src/problems/mod.rs");
+
+/// a fresh repository root with just a registry, removed when dropped
+struct Root(PathBuf);
+
+impl Root {
+    fn new(name: &str, registry: &str) -> Self {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src/problems")).unwrap();
+        fs::create_dir_all(dir.join("src/bin")).unwrap();
+        fs::write(dir.join("src/problems/mod.rs"), registry).unwrap();
+        Self(dir)
+    }
+
+    fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.0.join(path)).unwrap()
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.0.join(path).exists()
+    }
+}
+
+impl Drop for Root {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+#[test]
+fn creates_and_registers_a_day() {
+    let root = Root::new("create", REGISTRY);
+    let created = scaffold::new_day(&root.0, 15).unwrap();
+    assert_eq!(created.len(), 6);
+    assert!(created.iter().all(|path| path.exists()));
+
+    let module = root.read("src/problems/problem_15.rs");
+    assert!(module.contains("pub struct Problem15;"));
+    assert!(module.contains("include_str!(\"examples/day_15.txt\")"));
+    assert!(module.contains("fn check_shape(&self, _input: &str)"));
+    assert_eq!(root.read("src/problems/examples/day_15.txt"), "");
+    assert_eq!(root.read("inputs/input_15.txt"), "");
+    assert!(root.exists("src/bin/problem_15.rs"));
+    assert!(root.exists("tests/problem_15.rs"));
+
+    // `mod`s stay in rustfmt's order, and `DAYS` in day order
+    let registry = root.read("src/problems/mod.rs");
+    assert!(registry.contains("pub mod problem_14;\npub mod problem_15;\npub mod problem_2;\n"));
+    assert!(registry.contains("    &problem_14::Problem14,\n    &problem_15::Problem15,\n];"));
+}
+
+#[test]
+fn fills_gaps_in_order() {
+    let registry = "\
+use crate::solution::Day;
+
+pub mod problem_1;
+pub mod problem_12;
+pub mod problem_3;
+
+pub const DAYS: &[&dyn Day] = &[
+    &problem_1::Problem1,
+    &problem_3::Problem3,
+    &problem_12::Problem12,
+];
+";
+    let root = Root::new("gaps", registry);
+    scaffold::new_day(&root.0, 2).unwrap();
+    scaffold::new_day(&root.0, 20).unwrap();
+
+    assert_eq!(
+        root.read("src/problems/mod.rs"),
+        "\
+use crate::solution::Day;
+
+pub mod problem_1;
+pub mod problem_12;
+pub mod problem_2;
+pub mod problem_20;
+pub mod problem_3;
+
+pub const DAYS: &[&dyn Day] = &[
+    &problem_1::Problem1,
+    &problem_2::Problem2,
+    &problem_3::Problem3,
+    &problem_12::Problem12,
+    &problem_20::Problem20,
+];
+"
+    );
+}
+
+#[test]
+fn never_overwrites() {
+    let root = Root::new("existing", REGISTRY);
+    let module = Path::new("src/problems/problem_15.rs");
+    fs::write(root.0.join(module), "keep me").unwrap();
+
+    let err = scaffold::new_day(&root.0, 15).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    assert_eq!(root.read("src/problems/problem_15.rs"), "keep me");
+    assert_eq!(root.read("src/problems/mod.rs"), REGISTRY);
+    assert!(!root.exists("src/bin/problem_15.rs"));
+
+    // nor does it clobber a day that's already registered
+    let root = Root::new("registered", REGISTRY);
+    fs::write(root.0.join("src/problems/problem_3.rs"), "keep me").unwrap();
+    let err = scaffold::new_day(&root.0, 3).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    assert_eq!(root.read("src/problems/mod.rs"), REGISTRY);
+}
+
+#[test]
+fn rejects_days_outside_the_calendar() {
+    let root = Root::new("range", REGISTRY);
+    for day in [0, 26] {
+        let err = scaffold::new_day(&root.0, day).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+    assert_eq!(root.read("src/problems/mod.rs"), REGISTRY);
+}
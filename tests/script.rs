@@ -0,0 +1,142 @@
+//! Checks submarine scripts expand and report errors by line
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::problems::problem_2::{Aim, Command, Direct, Instructions};
+use advent_of_code_2021::script::Script;
+
+const ZIGZAG: &str = "\
+# a course that dips and climbs
+macro dip {
+    down 2  # dive
+    forward 1
+}
+macro zigzag {
+    dip
+    up 2
+}
+
+repeat 3 {
+    zigzag
+    repeat 2 {
+        forward 1
+    }
+}
+forward 5
+";
+
+#[test]
+fn expands_blocks_and_macros() {
+    let script: Script = ZIGZAG.parse().unwrap();
+    assert_eq!(script.len(), 3 * 5 + 1);
+
+    let commands: Vec<Command> = script.commands().take(6).collect();
+    assert_eq!(
+        commands,
+        [
+            Command::Down(2),
+            Command::Forward(1),
+            Command::Up(2),
+            Command::Forward(1),
+            Command::Forward(1),
+            Command::Down(2),
+        ]
+    );
+
+    // the same course written out in full
+    let flat: Instructions = "down 2\nforward 1\nup 2\nforward 1\nforward 1\n"
+        .repeat(3)
+        .parse()
+        .unwrap();
+    let mut flat = flat.commands().to_vec();
+    flat.push(Command::Forward(5));
+    assert_eq!(script.commands().collect::<Vec<_>>(), flat);
+    assert_eq!(script.navigate([&Direct])[0].depth, 0);
+}
+
+#[test]
+fn long_courses_stay_lazy() {
+    let script: Script =
+        "repeat 1000 {\n  repeat 1000 {\n    down 1\n    forward 1\n  }\n}\nrepeat 4000000000 {\n}"
+            .parse()
+            .unwrap();
+    assert_eq!(script.len(), 2_000_000);
+
+    let [position] = script.navigate([&Aim]);
+    assert_eq!(position.horizontal, 1_000_000);
+    assert_eq!(position.aim, 1_000_000);
+}
+
+#[test]
+fn errors_point_at_the_line() {
+    let err = |script: &str| script.parse::<Script>().unwrap_err();
+
+    assert_eq!(
+        err("forward 1\nrepeat 2 {\n    foward 3\n}"),
+        ParseError::BadToken {
+            line: 3,
+            column: 5,
+            found: "foward".to_string(),
+            expected: "`forward`, `up` or `down`"
+        }
+    );
+    assert_eq!(
+        err("forward 1\nrepeat 2 {\n    zigzag\n}"),
+        ParseError::BadToken {
+            line: 3,
+            column: 5,
+            found: "zigzag".to_string(),
+            expected: "a command or a macro defined above"
+        }
+    );
+    assert_eq!(
+        err("repeat 2 {\n  down x\n}"),
+        ParseError::BadToken {
+            line: 2,
+            column: 8,
+            found: "x".to_string(),
+            expected: "a whole number"
+        }
+    );
+    assert_eq!(
+        err("up 1\nrepeat 2 {\n  down 1\n"),
+        ParseError::BadLine {
+            line: 2,
+            expected: "a block that's closed with `}`"
+        }
+    );
+    assert_eq!(
+        err("up 1\n}"),
+        ParseError::BadToken {
+            line: 2,
+            column: 1,
+            found: "}".to_string(),
+            expected: "a command"
+        }
+    );
+    assert_eq!(
+        err("repeat many {\n}"),
+        ParseError::BadToken {
+            line: 1,
+            column: 8,
+            found: "many".to_string(),
+            expected: "a number of times to repeat"
+        }
+    );
+
+    // macros can only use the ones above them, so they can't recurse
+    assert_eq!(
+        err("macro loop {\n  loop\n}"),
+        ParseError::BadToken {
+            line: 2,
+            column: 3,
+            found: "loop".to_string(),
+            expected: "a command or a macro defined above"
+        }
+    );
+    assert!(matches!(
+        err("repeat 2 {\n  macro inner {\n  }\n}"),
+        ParseError::BadToken { line: 2, .. }
+    ));
+}
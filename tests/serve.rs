@@ -0,0 +1,191 @@
+//! Talks to the HTTP solving service over loopback
+//!
+//! Written by Trevor Leibert
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use advent_of_code_2021::serve::{self, Event, Server};
+use advent_of_code_2021::solution::Error;
+
+const DAY_1: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+/// starts a server on a free port, serving in the background for the rest of the test run
+fn start(timeout: Option<Duration>) -> SocketAddr {
+    let server = Server::bind(0, timeout).unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+    addr
+}
+
+/// sends a raw request, returning the status, headers and body of the response
+fn send(addr: SocketAddr, request: &str) -> (u16, String, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, head.to_string(), body.to_string())
+}
+
+/// the answer in a response body, without the timing line that follows it
+fn answer(body: &str) -> &str {
+    let (answer, time) = body.trim_end().rsplit_once('\n').unwrap();
+    assert!(time.starts_with("solved in "), "{}", body);
+    answer
+}
+
+fn post(addr: SocketAddr, path: &str, body: &str) -> (u16, String, String) {
+    send(
+        addr,
+        &format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            body.len(),
+            body
+        ),
+    )
+}
+
+#[test]
+fn solves_both_parts() {
+    let addr = start(None);
+
+    let (status, head, body) = post(addr, "/day/1/part/1", DAY_1);
+    assert_eq!(status, 200);
+    assert_eq!(answer(&body), "7");
+    assert!(head.contains("Server-Timing: solve;dur="), "{}", head);
+
+    let (status, _, body) = post(addr, "/day/1/part/2", DAY_1);
+    assert_eq!(status, 200);
+    assert_eq!(answer(&body), "5");
+
+    // multi-line answers keep their shape above the timing
+    let paper = "0,0\n1,0\n0,1\n\nfold along y=2\n";
+    let (status, _, body) = post(addr, "/day/13/part/2", paper);
+    assert_eq!(status, 200);
+    assert_eq!(answer(&body), "##\n#.");
+}
+
+#[test]
+fn params_come_from_the_query_string() {
+    let addr = start(None);
+
+    let (status, _, body) = post(addr, "/day/1/part/2?window=1", DAY_1);
+    assert_eq!(status, 200);
+    assert_eq!(answer(&body), "7");
+
+    // names and values are percent-decoded
+    let (status, _, body) = post(addr, "/day/1/part/2?win%64ow=%31", DAY_1);
+    assert_eq!((status, answer(&body)), (200, "7"));
+    let (status, _, body) = post(addr, "/day/1/part/2?window=+1", DAY_1);
+    assert_eq!((status, answer(&body)), (200, "7"));
+    let (status, _, body) = post(addr, "/day/1/part/2?window=1%3D2", DAY_1);
+    assert_eq!(status, 400);
+    assert!(body.contains("1=2"), "{}", body);
+    assert_eq!(post(addr, "/day/1/part/2?window=%3", DAY_1).0, 400);
+
+    // sizes that would allocate too much are capped
+    let (status, _, body) = post(addr, "/day/5/part/1?grid_size=1000000", "0,9 -> 5,9\n");
+    assert_eq!(status, 400);
+    assert!(body.contains("at most 4096"), "{}", body);
+    assert_eq!(
+        post(addr, "/day/1/part/2?window=1000000000000", DAY_1).0,
+        400
+    );
+    let (status, _, body) = post(
+        addr,
+        "/day/5/part/1?grid_size=10",
+        "0,9 -> 5,9\n0,9 -> 2,9\n",
+    );
+    assert_eq!((status, answer(&body)), (200, "3"));
+
+    let (status, _, body) = post(addr, "/day/1/part/2?widow=1", DAY_1);
+    assert_eq!(status, 400);
+    assert!(body.contains("window"), "{}", body);
+}
+
+#[test]
+fn concurrent_requests() {
+    let addr = start(None);
+
+    let clients: Vec<_> = (0..8)
+        .map(|_| thread::spawn(move || post(addr, "/day/1/part/1", DAY_1)))
+        .collect();
+    for client in clients {
+        assert_eq!(answer(&client.join().unwrap().2), "7");
+    }
+}
+
+#[test]
+fn errors_map_to_statuses() {
+    let addr = start(None);
+
+    assert_eq!(post(addr, "/day/26/part/1", DAY_1).0, 404);
+    assert_eq!(post(addr, "/day/1/part/3", DAY_1).0, 404);
+    assert_eq!(post(addr, "/nowhere", DAY_1).0, 404);
+
+    let (status, _, body) = post(addr, "/day/1/part/1", "not a depth\n");
+    assert_eq!(status, 422);
+    assert!(body.starts_with("solver panicked"), "{}", body);
+
+    let (status, head, _) = send(
+        addr,
+        "GET /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\n\r\n",
+    );
+    assert_eq!(status, 405);
+    assert!(head.contains("Allow: POST"), "{}", head);
+
+    let (status, _, _) = send(
+        addr,
+        "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\n\r\n",
+    );
+    assert_eq!(status, 411);
+
+    assert_eq!(send(addr, "nonsense\r\n\r\n").0, 400);
+
+    assert_eq!(serve::status(&Error::Unsolved), 501);
+}
+
+#[test]
+fn logs_to_the_callback() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let server = Server::bind(0, None).unwrap().with_log({
+        let events = Arc::clone(&events);
+        move |event: &Event| events.lock().unwrap().push(event.to_string())
+    });
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    post(addr, "/day/1/part/1", DAY_1);
+    post(addr, "/nowhere", DAY_1);
+    // the connection is only closed once the request has been logged
+
+    let mut events = events.lock().unwrap().clone();
+    events.sort();
+    assert_eq!(events.len(), 2, "{:?}", events);
+    assert!(
+        events[0].starts_with("POST /day/1/part/1 -> 200 ("),
+        "{:?}",
+        events
+    );
+    assert!(
+        events[1].starts_with("POST /nowhere -> 404 ("),
+        "{:?}",
+        events
+    );
+}
+
+#[test]
+fn slow_solves_time_out() {
+    let addr = start(Some(Duration::ZERO));
+
+    let (status, _, body) = post(addr, "/day/7/part/2", "16,1,2,0,4,2,7,1,2,14\n");
+    assert_eq!(status, 503);
+    assert!(body.starts_with("cancelled"), "{}", body);
+}
@@ -0,0 +1,87 @@
+//! Snapshot testing for rendered output
+//!
+//! Written by Trevor Leibert
+//!
+//! Expected renders are stored as plain text in `tests/snapshots/<name>.snap`.
+//! Run the tests with `UPDATE_SNAPSHOTS=1` to write (or rewrite) them from the
+//! current output, then review the changes before committing them.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// where a snapshot is stored
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.snap", name))
+}
+
+/// true if snapshots should be rewritten rather than checked
+fn update_mode() -> bool {
+    env::var("UPDATE_SNAPSHOTS").is_ok_and(|val| !val.is_empty() && val != "0")
+}
+
+/// checks a render against its stored snapshot, panicking with a diff if they differ
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = snapshot_path(name);
+
+    if update_mode() {
+        fs::write(&path, actual)
+            .unwrap_or_else(|err| panic!("couldn't write {}: {}", path.display(), err));
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+        panic!(
+            "no snapshot at {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+
+    if expected != actual {
+        panic!(
+            "snapshot `{}` doesn't match (- expected, + actual):\n{}\n\
+             run with UPDATE_SNAPSHOTS=1 if the new output is correct",
+            name,
+            diff(&expected, actual)
+        );
+    }
+}
+
+/// line by line diff of two texts, from their longest common subsequence
+fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.split('\n').collect();
+    let new: Vec<&str> = actual.split('\n').collect();
+
+    // lcs[i][j] is the length of the common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {:?}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(format!("- {:?}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {:?}", new[j]));
+            j += 1;
+        }
+    }
+
+    lines.join("\n")
+}
@@ -0,0 +1,204 @@
+//! Checks the day 1 sonar sweep counts
+//!
+//! Written by Trevor Leibert
+
+use advent_of_code_2021::problems::problem_1::{
+    count_depth_increases, count_depth_increases_window, Bucket, Change, Sonar, Step, Streak,
+    SweepOptions, SweepReport, Window,
+};
+use advent_of_code_2021::solution::Error;
+use std::io::ErrorKind;
+
+const DEPTHS: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+/// counts by summing every window, the slow way
+fn summed(depths: &[i64], size: usize) -> usize {
+    let sums: Vec<i64> = depths.windows(size).map(|w| w.iter().sum()).collect();
+    sums.windows(2).filter(|pair| pair[1] > pair[0]).count()
+}
+
+#[test]
+fn both_parts() {
+    assert_eq!(count_depth_increases::<1>(DEPTHS.as_bytes()).unwrap(), 7);
+    assert_eq!(count_depth_increases::<3>(DEPTHS.as_bytes()).unwrap(), 5);
+}
+
+#[test]
+fn matches_summing_windows() {
+    let depths: Vec<i64> = DEPTHS.lines().map(|line| line.parse().unwrap()).collect();
+    for size in 1..=12 {
+        assert_eq!(
+            count_depth_increases_window(DEPTHS.as_bytes(), size).unwrap(),
+            summed(&depths, size),
+            "window of {}",
+            size
+        );
+    }
+}
+
+#[test]
+fn shorter_than_the_window() {
+    assert_eq!(count_depth_increases::<3>("".as_bytes()).unwrap(), 0);
+    assert_eq!(
+        count_depth_increases::<3>("1\n2\n3\n".as_bytes()).unwrap(),
+        0
+    );
+    assert_eq!(
+        count_depth_increases::<3>("1\n2\n3\n4\n".as_bytes()).unwrap(),
+        1
+    );
+}
+
+#[test]
+fn blank_and_bad_lines() {
+    let padded = format!("\n{}\n  \n", DEPTHS.replace("210\n", "210\n\n"));
+    assert_eq!(count_depth_increases::<1>(padded.as_bytes()).unwrap(), 7);
+
+    let err = count_depth_increases::<1>("199\n200\nfathom\n".as_bytes()).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "line 3 isn't a depth");
+}
+
+#[test]
+fn windows_stream_lazily() {
+    let depths = DEPTHS.lines().map(|line| line.parse::<i64>().unwrap());
+    let windows: Vec<Window> = depths.sonar_windows(3).take(4).collect();
+    assert_eq!(
+        windows,
+        [
+            Window {
+                sum: 607,
+                change: None
+            },
+            Window {
+                sum: 618,
+                change: Some(Change::Increase)
+            },
+            Window {
+                sum: 618,
+                change: Some(Change::Same)
+            },
+            Window {
+                sum: 617,
+                change: Some(Change::Decrease)
+            },
+        ]
+    );
+
+    // an endless stream is fine, as only what's asked for is read
+    let zigzag = (0..).map(|n: i64| if n % 2 == 0 { n } else { -n });
+    let changes: Vec<Option<Change>> = zigzag
+        .sonar_windows(1)
+        .map(|window| window.change)
+        .take(3)
+        .collect();
+    assert_eq!(
+        changes,
+        [None, Some(Change::Decrease), Some(Change::Increase)]
+    );
+}
+
+#[test]
+fn sweep_report() {
+    let depths = DEPTHS.lines().map(|line| line.parse::<i64>().unwrap());
+    let report = SweepReport::new(depths, SweepOptions::default()).unwrap();
+
+    assert_eq!(report.windows, 10);
+    assert_eq!(
+        report.longest_increase,
+        Some(Streak {
+            first_line: 1,
+            last_line: 4,
+            steps: 3
+        })
+    );
+    assert_eq!(
+        report.longest_decrease,
+        Some(Streak {
+            first_line: 4,
+            last_line: 5,
+            steps: 1
+        })
+    );
+    assert_eq!(report.max_rise, Some(Step { line: 7, delta: 33 }));
+    assert_eq!(
+        report.max_drop,
+        Some(Step {
+            line: 5,
+            delta: -10
+        })
+    );
+    assert_eq!(
+        report.histogram,
+        [
+            Bucket { low: -10, count: 2 },
+            Bucket { low: 0, count: 5 },
+            Bucket { low: 20, count: 1 },
+            Bucket { low: 30, count: 1 },
+        ]
+    );
+    assert!(report.outliers.is_empty());
+
+    let text = report.to_string();
+    assert!(text.contains("Longest increase: 3 steps, lines 1 to 4"));
+    assert!(text.contains("Biggest drop: -10 at line 5"));
+}
+
+#[test]
+fn sweep_outliers_and_windows() {
+    let mut depths = [100; 20];
+    depths[10] = 200;
+    let options = SweepOptions {
+        outlier_deviations: 2.0,
+        ..SweepOptions::default()
+    };
+    let report = SweepReport::new(depths.iter().copied(), options).unwrap();
+    assert_eq!(
+        report.outliers,
+        [
+            Step {
+                line: 11,
+                delta: 100
+            },
+            Step {
+                line: 12,
+                delta: -100
+            }
+        ]
+    );
+
+    // windows of 3 line up with the part 2 count
+    let depths = DEPTHS.lines().map(|line| line.parse::<i64>().unwrap());
+    let options = SweepOptions {
+        window: 3,
+        ..SweepOptions::default()
+    };
+    let report = SweepReport::new(depths, options).unwrap();
+    assert_eq!(report.windows, 8);
+    assert_eq!(
+        report.longest_increase,
+        Some(Streak {
+            first_line: 4,
+            last_line: 10,
+            steps: 4
+        })
+    );
+
+    let empty = SweepReport::new(std::iter::empty(), options).unwrap();
+    assert_eq!(
+        (empty.windows, empty.max_rise, empty.outliers.len()),
+        (0, None, 0)
+    );
+}
+
+#[test]
+fn sweep_rejects_narrow_buckets() {
+    for bucket_width in [0, -10] {
+        let options = SweepOptions {
+            bucket_width,
+            ..SweepOptions::default()
+        };
+        let err = SweepReport::new([1, 2, 3].into_iter(), options).unwrap_err();
+        assert!(matches!(err, Error::Usage(_)), "{:?}", err);
+    }
+}